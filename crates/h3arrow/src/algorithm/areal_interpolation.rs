@@ -0,0 +1,211 @@
+use crate::array::from_geo::{CellFractions, ToCellFractions, ToCellsOptions};
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use arrow::array::{Array, Float64Array};
+use h3o::CellIndex;
+
+/// Values of polygons interpolated onto the cells covering them.
+pub struct ArealInterpolation {
+    /// the distinct cells covered by the geometries, sorted
+    pub cells: CellIndexArray,
+
+    /// the extensive values in the order the value columns were passed in
+    pub extensive: Vec<Float64Array>,
+
+    /// the intensive values in the order the value columns were passed in
+    pub intensive: Vec<Float64Array>,
+}
+
+pub trait ArealInterpolationOp {
+    /// Interpolate values given for each geometry onto the cells covering the geometries
+    /// using the area of the intersection of each cell with each geometry.
+    ///
+    /// * `extensive` values (counts, totals, ...) are distributed over the cells of a geometry
+    ///   in proportion to the intersected area. For each geometry, the values assigned to its
+    ///   cells sum up to the value of the geometry. The values of overlapping geometries are
+    ///   summed up.
+    /// * `intensive` values (densities, rates, ...) are averaged over all geometries
+    ///   intersecting a cell, weighted by the intersected area.
+    ///
+    /// All value arrays must have the same length as the geometry array. Null values are
+    /// ignored, cells without any non-null values are set to null. Geometries without an area
+    /// do not cover any cells.
+    fn areal_interpolation(
+        &self,
+        extensive: &[&Float64Array],
+        intensive: &[&Float64Array],
+        options: &ToCellsOptions,
+    ) -> Result<ArealInterpolation, Error>;
+}
+
+impl<T> ArealInterpolationOp for T
+where
+    T: ToCellFractions,
+{
+    fn areal_interpolation(
+        &self,
+        extensive: &[&Float64Array],
+        intensive: &[&Float64Array],
+        options: &ToCellsOptions,
+    ) -> Result<ArealInterpolation, Error> {
+        interpolate(&self.to_cell_fractions(options)?, extensive, intensive)
+    }
+}
+
+pub(crate) fn interpolate(
+    fractions: &CellFractions,
+    extensive: &[&Float64Array],
+    intensive: &[&Float64Array],
+) -> Result<ArealInterpolation, Error> {
+    let row_index = fractions.row_index.values();
+    let num_rows = match extensive.iter().chain(intensive.iter()).next() {
+        Some(values) => values.len(),
+        None => row_index.iter().max().map_or(0, |max| *max as usize + 1),
+    };
+    if extensive
+        .iter()
+        .chain(intensive.iter())
+        .any(|values| values.len() != num_rows)
+        || row_index.iter().any(|row| *row as usize >= num_rows)
+    {
+        return Err(Error::LengthMismatch);
+    }
+
+    // the intersected area of each cell and geometry in m²
+    let mut intersections = fractions
+        .cells
+        .iter()
+        .zip(row_index.iter())
+        .zip(fractions.fractions.values().iter())
+        .filter_map(|((cell, row), fraction)| {
+            cell.map(|cell| (cell, *row as usize, cell.area_m2() * fraction))
+        })
+        .collect::<Vec<_>>();
+
+    // the sum of the intersected areas is used as the area of the geometry. This is not exactly
+    // the area of the geometry, but ensures the extensive values are fully distributed.
+    let mut geometry_area = vec![0.0; num_rows];
+    for (_, row, area) in intersections.iter() {
+        geometry_area[*row] += area;
+    }
+
+    intersections.sort_by_key(|(cell, row, _)| (*cell, *row));
+
+    let mut cells = Vec::new();
+    let mut extensive_values = vec![Vec::new(); extensive.len()];
+    let mut intensive_values = vec![Vec::new(); intensive.len()];
+    for group in intersections.chunk_by(|a, b| a.0 == b.0) {
+        cells.push(group[0].0);
+
+        for (values, out) in extensive.iter().zip(extensive_values.iter_mut()) {
+            out.push(weighted(group, values, |row, area| {
+                area / geometry_area[row]
+            }));
+        }
+        for (values, out) in intensive.iter().zip(intensive_values.iter_mut()) {
+            out.push(
+                weighted(group, values, |_, area| area).map(|(sum, weight_sum)| sum / weight_sum),
+            );
+        }
+    }
+
+    Ok(ArealInterpolation {
+        cells: CellIndexArray::from(cells),
+        extensive: extensive_values
+            .into_iter()
+            .map(|values| {
+                Float64Array::from_iter(values.into_iter().map(|v| v.map(|(sum, _)| sum)))
+            })
+            .collect(),
+        intensive: intensive_values
+            .into_iter()
+            .map(Float64Array::from)
+            .collect(),
+    })
+}
+
+/// The sum of the non-null values weighted by `weight_fn` together with the sum of the weights.
+/// `None` when all values are null.
+fn weighted<F>(
+    group: &[(CellIndex, usize, f64)],
+    values: &Float64Array,
+    weight_fn: F,
+) -> Option<(f64, f64)>
+where
+    F: Fn(usize, f64) -> f64,
+{
+    group
+        .iter()
+        .filter(|(_, row, _)| values.is_valid(*row))
+        .fold(None, |acc, (_, row, area)| {
+            let weight = weight_fn(*row, *area);
+            let (sum, weight_sum) = acc.unwrap_or((0.0, 0.0));
+            Some((sum + values.value(*row) * weight, weight_sum + weight))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::ArealInterpolationOp;
+    use crate::array::from_geo::ToCellsOptions;
+    use arrow::array::{Array, Float64Array};
+    use geo_types::{Geometry, Rect};
+    use h3o::Resolution;
+
+    #[test]
+    fn areal_interpolation() {
+        let geometries = vec![
+            Some(Geometry::from(Rect::new((10.0, 10.0), (10.3, 10.3)))),
+            Some(Geometry::from(Rect::new((10.2, 10.2), (10.5, 10.5)))),
+            None,
+        ];
+        let extensive = Float64Array::from(vec![Some(1000.0), Some(500.0), Some(10.0)]);
+        let extensive_partial = Float64Array::from(vec![Some(1000.0), None, None]);
+        let intensive = Float64Array::from(vec![Some(2.0), Some(4.0), None]);
+
+        let interpolated = geometries
+            .as_slice()
+            .areal_interpolation(
+                &[&extensive, &extensive_partial],
+                &[&intensive],
+                &ToCellsOptions::from(Resolution::Six),
+            )
+            .unwrap();
+
+        let cells = interpolated.cells.primitive_array().values();
+        assert!(cells.windows(2).all(|w| w[0] < w[1]));
+
+        // the totals of the geometries are preserved
+        let total: f64 = interpolated.extensive[0].iter().flatten().sum();
+        assert!((total - 1500.0).abs() < 1e-6);
+        let total_partial: f64 = interpolated.extensive[1].iter().flatten().sum();
+        assert!((total_partial - 1000.0).abs() < 1e-6);
+        assert!(interpolated.extensive[1].null_count() > 0);
+
+        // cells within a single geometry keep its value, cells in the overlap get a mix
+        let intensive_values = interpolated.intensive[0].values();
+        assert_eq!(interpolated.intensive[0].null_count(), 0);
+        let is_close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        assert!(intensive_values
+            .iter()
+            .all(|v| (2.0 - 1e-9..=4.0 + 1e-9).contains(v)));
+        assert!(intensive_values.iter().any(|v| is_close(*v, 2.0)));
+        assert!(intensive_values.iter().any(|v| is_close(*v, 4.0)));
+        assert!(intensive_values
+            .iter()
+            .any(|v| *v > 2.0 + 1e-6 && *v < 4.0 - 1e-6));
+    }
+
+    #[test]
+    fn areal_interpolation_length_mismatch() {
+        let geometries = vec![Some(Geometry::from(Rect::new((10.0, 10.0), (10.3, 10.3))))];
+        assert!(geometries
+            .as_slice()
+            .areal_interpolation(
+                &[],
+                &[&Float64Array::from(Vec::<f64>::new())],
+                &ToCellsOptions::from(Resolution::Six),
+            )
+            .is_err());
+    }
+}
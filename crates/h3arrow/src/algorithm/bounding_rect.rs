@@ -1,4 +1,6 @@
-use crate::array::to_geo::{ToLines, ToPoints, ToPolygons};
+use crate::array::to_geo::{
+    unwrapped_line_bounding_rect, unwrapped_polygon_bounding_rect, ToLines, ToPoints, ToPolygons,
+};
 use crate::array::{CellIndexArray, DirectedEdgeIndexArray, VertexIndexArray};
 use geo::bounding_rect::BoundingRect;
 use geo_types::Rect;
@@ -12,7 +14,7 @@ impl BoundingRect<f64> for CellIndexArray {
                 .expect("polygon vec")
                 .into_iter()
                 .flatten()
-                .filter_map(|p| p.bounding_rect()),
+                .filter_map(|p| unwrapped_polygon_bounding_rect(&p)),
         )
     }
 }
@@ -40,21 +42,93 @@ impl BoundingRect<f64> for DirectedEdgeIndexArray {
                 .expect("line vec")
                 .into_iter()
                 .flatten()
-                .map(|line| line.bounding_rect()),
+                .filter_map(|line| unwrapped_line_bounding_rect(&line)),
         )
     }
 }
 
+/// Combine the rects to a single rect.
+///
+/// The longitudes are treated as circular, the combined rect is the narrowest rect covering
+/// all the rects. For rects crossing the antimeridian, the maximum longitude exceeds 180°.
 fn collect_rect<I>(iter: I) -> Option<Rect>
 where
     I: Iterator<Item = Rect>,
 {
-    iter.reduce(|a, b| {
-        Rect::new(
-            (a.min().x.min(b.min().x), a.min().y.min(b.min().y)),
-            (a.max().x.max(b.max().x), a.max().y.max(b.max().y)),
-        )
-    })
+    let mut min_y = f64::INFINITY;
+    let mut max_y = f64::NEG_INFINITY;
+    let mut intervals = Vec::new();
+    for rect in iter {
+        min_y = min_y.min(rect.min().y);
+        max_y = max_y.max(rect.max().y);
+
+        // normalize the start of the interval to [-180, 180)
+        let shift = -((rect.min().x + 180.0) / 360.0).floor() * 360.0;
+        intervals.push((rect.min().x + shift, rect.max().x + shift));
+    }
+    if intervals.is_empty() {
+        return None;
+    }
+    intervals.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+    // merge the overlapping intervals
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    // the rect leaves out the largest gap between the intervals. The gap
+    // across the antimeridian wraps around the globe.
+    let first = merged[0];
+    let max_end = merged.iter().map(|(_, end)| *end).fold(first.1, f64::max);
+    let mut gap = (first.0 + 360.0 - max_end, first.0, max_end);
+    for window in merged.windows(2) {
+        let gap_width = window[1].0 - window[0].1;
+        if gap_width > gap.0 {
+            gap = (gap_width, window[1].0, window[0].1 + 360.0);
+        }
+    }
+    let (min_x, max_x) = if gap.0 <= 0.0 {
+        (-180.0, 180.0)
+    } else {
+        (gap.1, gap.2)
+    };
+
+    Some(Rect::new((min_x, min_y), (max_x, max_y)))
 }
 
 // todo: H3ListArray
+
+#[cfg(test)]
+mod tests {
+    use crate::array::CellIndexArray;
+    use geo::BoundingRect;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn bounding_rect_antimeridian() {
+        let cells = CellIndexArray::from(vec![
+            LatLng::new(10.0, 179.0).unwrap().to_cell(Resolution::Five),
+            LatLng::new(12.0, -179.0).unwrap().to_cell(Resolution::Five),
+        ]);
+        let rect = cells.bounding_rect().unwrap();
+        assert!(rect.width() < 5.0);
+        assert!(rect.min().x < 179.0);
+        assert!(rect.max().x > 181.0);
+    }
+
+    #[test]
+    fn bounding_rect() {
+        let cells = CellIndexArray::from(vec![
+            LatLng::new(10.0, -100.0).unwrap().to_cell(Resolution::Five),
+            LatLng::new(12.0, 20.0).unwrap().to_cell(Resolution::Five),
+        ]);
+        let rect = cells.bounding_rect().unwrap();
+        assert!(rect.min().x < -100.0);
+        assert!(rect.max().x > 20.0);
+        assert!(rect.max().x < 21.0);
+    }
+}
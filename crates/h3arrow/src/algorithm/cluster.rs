@@ -0,0 +1,150 @@
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::{Array, Float64Array, UInt32Array};
+use h3o::CellIndex;
+use std::collections::hash_map::Entry;
+use std::collections::VecDeque;
+
+pub trait ClusterOp {
+    /// Density-based clustering (DBSCAN) of the cells.
+    ///
+    /// The neighborhood of a cell is its grid disk of size `k`. A cell is a core cell of a cluster
+    /// when the summed weights of all cells within its neighborhood reach `min_weight`. Cells
+    /// in the neighborhood of a core cell are assigned to the cluster of the core cell.
+    ///
+    /// The optional `weights` array provides a weight for each cell, without it every cell
+    /// is weighted with 1.0. Duplicate cells contribute with all of their weights; null weights
+    /// do not contribute.
+    ///
+    /// Returns the cluster id for each position of the input array. Cluster ids are assigned
+    /// in the order of appearance of their first core cell. Noise and null cells are set to null.
+    fn dbscan(
+        &self,
+        k: u32,
+        min_weight: f64,
+        weights: Option<&Float64Array>,
+    ) -> Result<UInt32Array, Error>;
+}
+
+impl ClusterOp for CellIndexArray {
+    fn dbscan(
+        &self,
+        k: u32,
+        min_weight: f64,
+        weights: Option<&Float64Array>,
+    ) -> Result<UInt32Array, Error> {
+        if let Some(weights) = weights {
+            if weights.len() != self.len() {
+                return Err(Error::LengthMismatch);
+            }
+        }
+
+        // sum up the weights of the distinct cells
+        let mut cellweights: HashMap<CellIndex, f64> = HashMap::with_capacity(self.len());
+        for (pos, cell) in self.iter().enumerate() {
+            if let Some(cell) = cell {
+                let weight = match weights {
+                    Some(weights) if weights.is_null(pos) => 0.0,
+                    Some(weights) => weights.value(pos),
+                    None => 1.0,
+                };
+                *cellweights.entry(cell).or_insert(0.0) += weight;
+            }
+        }
+
+        let is_core = |cell: CellIndex| -> bool {
+            cell.grid_disk::<Vec<_>>(k)
+                .into_iter()
+                .filter_map(|neighbor| cellweights.get(&neighbor))
+                .sum::<f64>()
+                >= min_weight
+        };
+
+        let mut cellclusters: HashMap<CellIndex, u32> = HashMap::with_capacity(cellweights.len());
+        let mut visited_core: HashMap<CellIndex, bool> = HashMap::with_capacity(cellweights.len());
+        let mut next_cluster_id = 0u32;
+
+        for seed in self.iter().flatten() {
+            if cellclusters.contains_key(&seed) {
+                continue;
+            }
+            let seed_is_core = match visited_core.entry(seed) {
+                Entry::Occupied(e) => *e.get(),
+                Entry::Vacant(e) => *e.insert(is_core(seed)),
+            };
+            if !seed_is_core {
+                continue;
+            }
+
+            let cluster_id = next_cluster_id;
+            next_cluster_id += 1;
+
+            cellclusters.insert(seed, cluster_id);
+            let mut queue = VecDeque::from([seed]);
+            while let Some(core_cell) = queue.pop_front() {
+                for neighbor in core_cell.grid_disk::<Vec<_>>(k) {
+                    if !cellweights.contains_key(&neighbor) {
+                        continue;
+                    }
+                    if let Entry::Vacant(e) = cellclusters.entry(neighbor) {
+                        e.insert(cluster_id);
+
+                        // only core cells expand the cluster further
+                        let neighbor_is_core = match visited_core.entry(neighbor) {
+                            Entry::Occupied(e) => *e.get(),
+                            Entry::Vacant(e) => *e.insert(is_core(neighbor)),
+                        };
+                        if neighbor_is_core {
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(self
+            .iter()
+            .map(|cell| cell.and_then(|cell| cellclusters.get(&cell).copied()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::ClusterOp;
+    use crate::array::CellIndexArray;
+    use arrow::array::{Array, Float64Array};
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn dbscan() {
+        let center_a = LatLng::new(10.3, 45.1).unwrap().to_cell(Resolution::Nine);
+        let center_b = LatLng::new(5.3, -5.1).unwrap().to_cell(Resolution::Nine);
+        let lonely = LatLng::new(-20.3, 12.1).unwrap().to_cell(Resolution::Nine);
+
+        let mut cells = center_a.grid_disk::<Vec<_>>(1);
+        cells.extend(center_b.grid_disk::<Vec<_>>(2));
+        cells.push(lonely);
+        let cells = CellIndexArray::from(cells);
+
+        let clusters = cells.dbscan(1, 4.0, None).unwrap();
+        assert_eq!(clusters.len(), cells.len());
+        assert_eq!(clusters.null_count(), 1);
+        assert!(clusters.iter().take(7).all(|c| c == Some(0)));
+        assert!(clusters.iter().skip(7).take(19).all(|c| c == Some(1)));
+        assert!(clusters.is_null(cells.len() - 1));
+
+        // weight the lonely cell high enough to form a cluster on its own
+        let weights = Float64Array::from_iter_values((0..cells.len()).map(|pos| {
+            if pos == cells.len() - 1 {
+                5.0
+            } else {
+                1.0
+            }
+        }));
+        let clusters = cells.dbscan(1, 4.0, Some(&weights)).unwrap();
+        assert_eq!(clusters.null_count(), 0);
+        assert_eq!(clusters.value(cells.len() - 1), 2);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::array::to_geo::{AntimeridianMode, ToMultiPolygons};
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use arrow::array::{Array, ArrayRef, UInt32Array};
+use arrow::compute::{partition, sort_to_indices, take};
+use geo_types::MultiPolygon;
+use h3o::CellIndex;
+#[cfg(feature = "rayon")]
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::ops::Range;
+
+/// The dissolved cells of each distinct key.
+pub struct DissolvedGroups {
+    /// the distinct keys, sorted. A null key forms a group of its own.
+    pub keys: ArrayRef,
+
+    /// the dissolved cells of each key. Groups without any non-null cells result in
+    /// empty multipolygons.
+    pub multipolygons: Vec<MultiPolygon>,
+}
+
+pub trait DissolveOp {
+    /// Group the cells by the value at the same position in `keys` and dissolve the cells of each
+    /// group into a multipolygon. The groups are dissolved in parallel when the `rayon` feature
+    /// is enabled.
+    fn dissolve_by_key(
+        &self,
+        keys: &dyn Array,
+        use_degrees: bool,
+    ) -> Result<DissolvedGroups, Error>;
+
+    /// [`Self::dissolve_by_key`] with the polygons crossing the antimeridian handled as
+    /// specified by `mode`.
+    fn dissolve_by_key_antimeridian(
+        &self,
+        keys: &dyn Array,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<DissolvedGroups, Error>;
+}
+
+impl DissolveOp for CellIndexArray {
+    fn dissolve_by_key(
+        &self,
+        keys: &dyn Array,
+        use_degrees: bool,
+    ) -> Result<DissolvedGroups, Error> {
+        self.dissolve_by_key_antimeridian(keys, use_degrees, AntimeridianMode::Keep)
+    }
+
+    fn dissolve_by_key_antimeridian(
+        &self,
+        keys: &dyn Array,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<DissolvedGroups, Error> {
+        if keys.len() != self.len() {
+            return Err(Error::LengthMismatch);
+        }
+
+        let indices = sort_to_indices(keys, None, None)?;
+        let sorted_keys = take(keys, &indices, None)?;
+        let groups = if sorted_keys.is_empty() {
+            vec![]
+        } else {
+            partition(std::slice::from_ref(&sorted_keys))?.ranges()
+        };
+
+        let dissolve_group = |group: &Range<usize>| {
+            let mut cells = indices.values()[group.clone()]
+                .iter()
+                .filter_map(|pos| self.get(*pos as usize))
+                .collect::<Vec<CellIndex>>();
+            cells.sort_unstable();
+            cells.dedup();
+            CellIndexArray::from(cells).to_multipolygons_antimeridian(use_degrees, mode)
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let multipolygons = groups
+            .iter()
+            .map(dissolve_group)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        #[cfg(feature = "rayon")]
+        let multipolygons = groups
+            .as_slice()
+            .into_par_iter()
+            .map(dissolve_group)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let group_starts = groups
+            .iter()
+            .map(|group| group.start as u32)
+            .collect::<UInt32Array>();
+        Ok(DissolvedGroups {
+            keys: take(sorted_keys.as_ref(), &group_starts, None)?,
+            multipolygons,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::DissolveOp;
+    use crate::array::to_geo::ToPolygons;
+    use crate::array::CellIndexArray;
+    use arrow::array::{Array, AsArray, StringArray};
+    use geo::Area;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn dissolve_by_key() {
+        let cell = LatLng::new(10.3, 45.1).unwrap().to_cell(Resolution::Six);
+        let disk = cell.grid_disk::<Vec<_>>(1);
+        let other = LatLng::new(-20.0, 10.0).unwrap().to_cell(Resolution::Six);
+
+        let mut cells = disk.clone();
+        cells.extend([other, disk[0]]);
+        let keys = StringArray::from(vec![
+            Some("b"),
+            Some("b"),
+            Some("b"),
+            Some("b"),
+            Some("b"),
+            Some("b"),
+            Some("b"),
+            Some("a"),
+            None,
+        ]);
+        let cells = CellIndexArray::from(cells);
+
+        let groups = cells.dissolve_by_key(&keys, true).unwrap();
+        assert_eq!(groups.keys.len(), 3);
+        let group_keys = groups.keys.as_string::<i32>();
+        assert!(group_keys.is_null(0));
+        assert_eq!(group_keys.value(1), "a");
+        assert_eq!(group_keys.value(2), "b");
+
+        assert_eq!(groups.multipolygons.len(), 3);
+        assert_eq!(groups.multipolygons[1].0.len(), 1);
+        assert_eq!(groups.multipolygons[2].0.len(), 1);
+        assert_eq!(groups.multipolygons[2].0[0].interiors().len(), 0);
+
+        // the disk dissolves into a single polygon covering all of its cells
+        let cells_area: f64 = CellIndexArray::from(disk)
+            .to_polygons(true)
+            .unwrap()
+            .iter()
+            .flatten()
+            .map(|polygon| polygon.unsigned_area())
+            .sum();
+        assert!((groups.multipolygons[2].unsigned_area() / cells_area - 1.0).abs() < 1e-6);
+    }
+}
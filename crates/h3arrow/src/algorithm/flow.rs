@@ -0,0 +1,143 @@
+use crate::array::{CellIndexArray, DirectedEdgeIndexArray, H3ListArray};
+use crate::error::Error;
+use ahash::HashMap;
+use arrow::array::{OffsetSizeTrait, UInt64Array};
+use h3o::{CellIndex, DirectedEdgeIndex, Resolution};
+
+/// Number of movements across each directed edge.
+pub struct EdgeFlows {
+    /// the distinct directed edges, sorted
+    pub edges: DirectedEdgeIndexArray,
+
+    /// the number of movements across each edge
+    pub count: UInt64Array,
+}
+
+/// Number of sequences for each pair of origin and destination cell.
+pub struct OdFlows {
+    /// the origin cells. Pairs are sorted by origin and destination.
+    pub origin: CellIndexArray,
+
+    /// the destination cells
+    pub destination: CellIndexArray,
+
+    /// the number of sequences starting in the origin and ending in the destination
+    pub count: UInt64Array,
+}
+
+pub trait FlowOp {
+    /// Count the movements between consecutive cells of the cell sequences for each directed edge.
+    ///
+    /// Only movements between neighboring cells are counted, so gaps in the sequences should be
+    /// filled beforehand. Repeated cells and null values are skipped.
+    fn edge_flows(&self) -> Result<EdgeFlows, Error>;
+
+    /// Count the cell sequences for each pair of origin and destination cell.
+    ///
+    /// The origin and destination are the first and the last non-null cell of a sequence, changed
+    /// to the coarser `resolution`. Sequences without cells or with cells of a coarser resolution
+    /// are skipped.
+    fn od_flows(&self, resolution: Resolution) -> Result<OdFlows, Error>;
+}
+
+impl<O: OffsetSizeTrait> FlowOp for H3ListArray<CellIndex, O> {
+    fn edge_flows(&self) -> Result<EdgeFlows, Error> {
+        let mut flows: HashMap<DirectedEdgeIndex, u64> = HashMap::default();
+        for cells in self.iter_arrays().flatten() {
+            let cells = cells?;
+            let mut previous: Option<CellIndex> = None;
+            for cell in cells.iter().flatten() {
+                if let Some(edge) = previous.and_then(|previous| previous.edge(cell)) {
+                    *flows.entry(edge).or_insert(0) += 1;
+                }
+                previous = Some(cell);
+            }
+        }
+
+        let mut flows = flows.into_iter().collect::<Vec<_>>();
+        flows.sort_unstable_by_key(|(edge, _)| *edge);
+        Ok(EdgeFlows {
+            edges: flows.iter().map(|(edge, _)| *edge).collect(),
+            count: flows
+                .iter()
+                .map(|(_, count)| *count)
+                .collect::<Vec<_>>()
+                .into(),
+        })
+    }
+
+    fn od_flows(&self, resolution: Resolution) -> Result<OdFlows, Error> {
+        let mut flows: HashMap<(CellIndex, CellIndex), u64> = HashMap::default();
+        for cells in self.iter_arrays().flatten() {
+            let cells = cells?;
+            let mut valid_cells = cells.iter().flatten();
+            let Some(first) = valid_cells.next() else {
+                continue;
+            };
+            let last = valid_cells.last().unwrap_or(first);
+            if let (Some(origin), Some(destination)) =
+                (first.parent(resolution), last.parent(resolution))
+            {
+                *flows.entry((origin, destination)).or_insert(0) += 1;
+            }
+        }
+
+        let mut flows = flows.into_iter().collect::<Vec<_>>();
+        flows.sort_unstable_by_key(|(od, _)| *od);
+        Ok(OdFlows {
+            origin: flows.iter().map(|((origin, _), _)| *origin).collect(),
+            destination: flows
+                .iter()
+                .map(|((_, destination), _)| *destination)
+                .collect(),
+            count: flows
+                .iter()
+                .map(|(_, count)| *count)
+                .collect::<Vec<_>>()
+                .into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::{CoordinateArrays, FlowOp, TrajectoryOp};
+    use arrow::array::{ArrayRef, Float64Array, TimestampSecondArray, UInt64Array};
+    use h3o::{LatLng, Resolution};
+    use std::sync::Arc;
+
+    #[test]
+    fn flows() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![10.3, 12.0, 10.3, 12.0]),
+            lng: Float64Array::from(vec![45.1, 46.0, 45.1, 46.0]),
+        };
+        let timestamps = TimestampSecondArray::from(vec![0, 100, 0, 100]);
+        let track_ids: ArrayRef = Arc::new(UInt64Array::from(vec![1, 1, 2, 2]));
+        let sequences = coordinates
+            .to_cell_sequences(&timestamps, Some(&track_ids), Resolution::Five, true, true)
+            .unwrap();
+        let cell_lists = sequences.to_cell_lists().unwrap();
+
+        let edge_flows = cell_lists.edge_flows().unwrap();
+        let distance = LatLng::new(10.3, 45.1)
+            .unwrap()
+            .to_cell(Resolution::Five)
+            .grid_distance(LatLng::new(12.0, 46.0).unwrap().to_cell(Resolution::Five))
+            .unwrap() as usize;
+        assert_eq!(edge_flows.edges.len(), distance);
+        assert!(edge_flows.count.values().iter().all(|count| *count == 2));
+
+        let od_flows = cell_lists.od_flows(Resolution::Two).unwrap();
+        assert_eq!(od_flows.count.len(), 1);
+        assert_eq!(od_flows.count.value(0), 2);
+        assert_eq!(
+            od_flows.origin.get(0),
+            Some(LatLng::new(10.3, 45.1).unwrap().to_cell(Resolution::Two))
+        );
+        assert_eq!(
+            od_flows.destination.get(0),
+            Some(LatLng::new(12.0, 46.0).unwrap().to_cell(Resolution::Two))
+        );
+    }
+}
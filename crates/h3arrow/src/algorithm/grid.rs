@@ -1,11 +1,14 @@
+use crate::algorithm::radius::{arc_distance_rads, disk_k, grow_disk_k, to_xyz, GeodesicCircle};
 use crate::array::{CellIndexArray, H3Array, H3ListArray, H3ListArrayBuilder};
 use crate::error::Error;
 use ahash::{HashMap, HashMapExt};
 use arrow::array::{
-    Array, GenericListArray, GenericListBuilder, OffsetSizeTrait, PrimitiveArray, UInt32Array,
-    UInt32Builder,
+    Array, Float64Builder, GenericListArray, GenericListBuilder, OffsetSizeTrait, PrimitiveArray,
+    UInt32Array, UInt32Builder,
 };
-use h3o::{max_grid_disk_size, CellIndex};
+use h3o::{max_grid_disk_size, CellIndex, LatLng, EARTH_RADIUS_KM};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use std::cmp::{max, min};
 use std::collections::hash_map::Entry;
 
@@ -20,6 +23,18 @@ pub enum KAggregationMethod {
     Max,
 }
 
+/// The distance between cells used by [`GridDiskWithinOp::grid_disk_within_m`].
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub enum MetricDistanceMode {
+    /// great-circle distance between the centroids of the cells
+    #[default]
+    Centroid,
+
+    /// shortest great-circle distance between the boundaries of the cells. Neighbouring
+    /// cells have a distance of 0.
+    Boundary,
+}
+
 pub struct GridDiskAggregateK {
     pub cells: CellIndexArray,
     pub distances: UInt32Array,
@@ -46,6 +61,21 @@ where
     ) -> Result<GridDiskAggregateK, Error>;
 }
 
+pub trait GridDiskWithinOp {
+    /// The cells within `distance_m` meters of each cell, together with their distances
+    /// in meters as `Float64` lists.
+    ///
+    /// In contrast to `grid_disk`, the size of the buffer does not vary with the latitude
+    /// or pentagon distortion. `k` is picked for each cell from the edge length of its
+    /// resolution and the cells of the disk are filtered by the exact distance
+    /// according to `mode`.
+    fn grid_disk_within_m<O: OffsetSizeTrait>(
+        &self,
+        distance_m: f64,
+        mode: MetricDistanceMode,
+    ) -> Result<GridDiskDistances<O>, Error>;
+}
+
 impl GridOp for H3Array<CellIndex> {
     fn grid_disk<O: OffsetSizeTrait>(&self, k: u32) -> Result<H3ListArray<CellIndex, O>, Error> {
         let mut builder = H3ListArrayBuilder::with_capacity(
@@ -120,6 +150,136 @@ impl GridOp for H3Array<CellIndex> {
     }
 }
 
+impl GridDiskWithinOp for CellIndexArray {
+    fn grid_disk_within_m<O: OffsetSizeTrait>(
+        &self,
+        distance_m: f64,
+        mode: MetricDistanceMode,
+    ) -> Result<GridDiskDistances<O>, Error> {
+        let disk = |cell: Option<CellIndex>| {
+            cell.map(|cell| metric_disk(cell, distance_m, mode))
+                .transpose()
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let disks = self.iter().map(disk).collect::<Result<Vec<_>, Error>>()?;
+
+        #[cfg(feature = "rayon")]
+        let disks = self
+            .iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(disk)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut grid_cells_builder = H3ListArrayBuilder::with_capacity(self.len(), self.len());
+        let mut grid_distances_builder = GenericListBuilder::with_capacity(
+            Float64Builder::with_capacity(self.len()),
+            self.len(),
+        );
+
+        for disk in disks {
+            let is_valid = match disk {
+                Some(disk) => {
+                    for (grid_cell, grid_distance_m) in disk {
+                        grid_cells_builder.values().append_value(grid_cell);
+                        grid_distances_builder
+                            .values()
+                            .append_value(grid_distance_m);
+                    }
+                    true
+                }
+                None => false,
+            };
+
+            grid_cells_builder.append(is_valid);
+            grid_distances_builder.append(is_valid)
+        }
+
+        Ok(GridDiskDistances {
+            cells: grid_cells_builder.finish()?,
+            distances: grid_distances_builder.finish(),
+        })
+    }
+}
+
+/// The cells within `distance_m` meters of `cell` with their distances in meters.
+fn metric_disk(
+    cell: CellIndex,
+    distance_m: f64,
+    mode: MetricDistanceMode,
+) -> Result<Vec<(CellIndex, f64)>, Error> {
+    let centroid = LatLng::from(cell);
+    let circle = GeodesicCircle::try_new(centroid, distance_m)?;
+    let boundary = cell_boundary_xyz(cell);
+    let distance_rads = distance_m / (EARTH_RADIUS_KM * 1000.0);
+
+    let measure = |grid_cell: CellIndex| -> f64 {
+        match mode {
+            MetricDistanceMode::Centroid => centroid.distance_m(LatLng::from(grid_cell)),
+            MetricDistanceMode::Boundary if grid_cell == cell => 0.0,
+            MetricDistanceMode::Boundary => {
+                boundary_distance_rads(&boundary, &cell_boundary_xyz(grid_cell))
+                    * EARTH_RADIUS_KM
+                    * 1000.0
+            }
+        }
+    };
+
+    // cells which may be within the distance. These form a connected area around the cell.
+    let in_reach = |grid_cell: CellIndex| match mode {
+        MetricDistanceMode::Centroid => circle.intersects(grid_cell, cell),
+        MetricDistanceMode::Boundary => measure(grid_cell) <= distance_m,
+    };
+
+    let edge_length_rads = cell.resolution().edge_length_rads();
+    let reach_rads = match mode {
+        MetricDistanceMode::Centroid => distance_rads,
+        MetricDistanceMode::Boundary => distance_rads + 2.0 * edge_length_rads,
+    };
+    let mut k = disk_k(reach_rads, cell.resolution())?;
+    loop {
+        let disk = cell.grid_disk_distances::<Vec<_>>(k);
+
+        // grow the disk when the edge length underestimates the size of the cells
+        if disk
+            .iter()
+            .any(|(grid_cell, grid_k)| *grid_k == k && in_reach(*grid_cell))
+        {
+            k = grow_disk_k(k, cell.resolution())?;
+            continue;
+        }
+
+        return Ok(disk
+            .into_iter()
+            .filter_map(|(grid_cell, _)| {
+                let grid_distance_m = measure(grid_cell);
+                (grid_distance_m <= distance_m).then_some((grid_cell, grid_distance_m))
+            })
+            .collect());
+    }
+}
+
+fn cell_boundary_xyz(cell: CellIndex) -> Vec<[f64; 3]> {
+    cell.boundary().iter().map(|ll| to_xyz(*ll)).collect()
+}
+
+/// The shortest distance between the boundaries of two non-overlapping cells. This is
+/// always located at a vertex of one of the cells.
+fn boundary_distance_rads(a: &[[f64; 3]], b: &[[f64; 3]]) -> f64 {
+    let vertexes_to_edges = |vertexes: &[[f64; 3]], ring: &[[f64; 3]]| {
+        vertexes
+            .iter()
+            .flat_map(|v| {
+                ring.iter()
+                    .zip(ring.iter().cycle().skip(1))
+                    .map(move |(start, end)| arc_distance_rads(v, start, end))
+            })
+            .fold(f64::INFINITY, f64::min)
+    };
+    vertexes_to_edges(a, b).min(vertexes_to_edges(b, a))
+}
+
 fn build_grid_disk<F, O: OffsetSizeTrait>(
     cellindexarray: &CellIndexArray,
     k: u32,
@@ -169,3 +329,98 @@ where
         distances: grid_distances,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::{GridDiskWithinOp, MetricDistanceMode};
+    use crate::array::CellIndexArray;
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::Float64Type;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn grid_disk_within_m() {
+        let cell = LatLng::new(64.1, -21.9).unwrap().to_cell(Resolution::Eight);
+        let cells = CellIndexArray::from(vec![Some(cell), None]);
+        let distance_m = 3_000.0;
+
+        let centroid = cells
+            .grid_disk_within_m::<i64>(distance_m, MetricDistanceMode::Centroid)
+            .unwrap();
+        assert_eq!(centroid.cells.len(), 2);
+        assert!(centroid.distances.is_null(1));
+
+        let grid_cells = centroid
+            .cells
+            .iter_arrays()
+            .next()
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let distances = centroid.distances.value(0);
+        let distances = distances.as_primitive::<Float64Type>();
+        assert_eq!(grid_cells.len(), distances.len());
+
+        // brute force using a disk far larger than the buffer
+        let expected = cell
+            .grid_disk::<Vec<_>>(20)
+            .into_iter()
+            .filter(|grid_cell| {
+                LatLng::from(*grid_cell).distance_m(LatLng::from(cell)) <= distance_m
+            })
+            .count();
+        assert_eq!(grid_cells.len(), expected);
+        assert!(distances.values().iter().all(|d| *d <= distance_m));
+
+        let boundary = cells
+            .grid_disk_within_m::<i64>(distance_m, MetricDistanceMode::Boundary)
+            .unwrap();
+        let boundary_cells = boundary
+            .cells
+            .iter_arrays()
+            .next()
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert!(boundary_cells.len() > grid_cells.len());
+
+        // the direct neighbours touch the cell
+        let boundary_distances = boundary.distances.value(0);
+        let boundary_distances = boundary_distances.as_primitive::<Float64Type>();
+        for (grid_cell, d) in boundary_cells
+            .iter()
+            .zip(boundary_distances.values().iter())
+        {
+            if cell.is_neighbor_with(grid_cell.unwrap()).unwrap() {
+                assert!(*d < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn grid_disk_within_m_huge_distance() {
+        let cell = LatLng::new(64.1, -21.9).unwrap().to_cell(Resolution::One);
+        let cells = CellIndexArray::from(vec![cell]);
+
+        // the disk covers the whole sphere
+        for mode in [MetricDistanceMode::Centroid, MetricDistanceMode::Boundary] {
+            let disk = cells.grid_disk_within_m::<i64>(1e12, mode).unwrap();
+            assert_eq!(
+                disk.cells
+                    .iter_arrays()
+                    .next()
+                    .unwrap()
+                    .unwrap()
+                    .unwrap()
+                    .len() as u64,
+                Resolution::One.cell_count()
+            );
+        }
+
+        // too many cells to allocate
+        let cells = CellIndexArray::from(vec![cell.center_child(Resolution::Twelve).unwrap()]);
+        assert!(cells
+            .grid_disk_within_m::<i64>(1e12, MetricDistanceMode::Centroid)
+            .is_err());
+    }
+}
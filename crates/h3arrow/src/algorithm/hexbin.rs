@@ -0,0 +1,367 @@
+use crate::algorithm::CoordinateArrays;
+use crate::array::{CellIndexArray, ResolutionArray};
+use crate::error::Error;
+use ahash::HashMap;
+use arrow::array::{Array, Float64Array, UInt64Array};
+use h3o::{CellIndex, LatLng, Resolution};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::hash::Hash;
+
+/// The H3 resolution(s) to bin the coordinates into.
+#[derive(Clone, Copy)]
+pub enum HexbinResolution<'a> {
+    /// bin all coordinates using the same resolution
+    Fixed(Resolution),
+
+    /// bin each coordinate using the resolution at the same position in the array.
+    /// Coordinates with a null resolution are skipped.
+    PerCoordinate(&'a ResolutionArray),
+}
+
+impl From<Resolution> for HexbinResolution<'_> {
+    fn from(resolution: Resolution) -> Self {
+        Self::Fixed(resolution)
+    }
+}
+
+impl<'a> From<&'a ResolutionArray> for HexbinResolution<'a> {
+    fn from(resolutions: &'a ResolutionArray) -> Self {
+        Self::PerCoordinate(resolutions)
+    }
+}
+
+/// Aggregates of a value column for each cell of a [`Hexbins`] instance.
+///
+/// Null values are ignored, cells without any non-null values are set to null.
+pub struct HexbinAggregates {
+    pub sum: Float64Array,
+    pub mean: Float64Array,
+    pub min: Float64Array,
+    pub max: Float64Array,
+}
+
+pub struct Hexbins {
+    /// the distinct cells, sorted
+    pub cells: CellIndexArray,
+
+    /// the number of coordinates located in each cell
+    pub count: UInt64Array,
+
+    /// the aggregates of the value columns in the order the value columns were passed in
+    pub values: Vec<HexbinAggregates>,
+}
+
+pub trait HexbinOp {
+    /// Bin the coordinates into cells and aggregate the optional `values` for each cell.
+    ///
+    /// This is equivalent to converting all coordinates to cells and grouping by the cells
+    /// afterwards, without building the intermediate per-coordinate cell array.
+    ///
+    /// All `values` arrays must have the same length as the coordinate arrays. Coordinates
+    /// with a null latitude or longitude are skipped.
+    fn hexbin<'a>(
+        &self,
+        resolution: impl Into<HexbinResolution<'a>>,
+        values: &[&Float64Array],
+        use_degrees: bool,
+    ) -> Result<Hexbins, Error>;
+}
+
+impl HexbinOp for CoordinateArrays {
+    fn hexbin<'a>(
+        &self,
+        resolution: impl Into<HexbinResolution<'a>>,
+        values: &[&Float64Array],
+        use_degrees: bool,
+    ) -> Result<Hexbins, Error> {
+        let locator = CellLocator::try_new(self, resolution.into(), use_degrees)?;
+        let (cells, count, values) =
+            bin_positions(self.lat.len(), values, |pos| locator.cell(pos))?;
+        Ok(Hexbins {
+            cells: cells.into_iter().collect(),
+            count,
+            values,
+        })
+    }
+}
+
+/// Locates the cell of each coordinate at the requested resolution.
+pub(crate) struct CellLocator<'a> {
+    coordinates: &'a CoordinateArrays,
+    resolution: HexbinResolution<'a>,
+    resolutions: Option<Vec<Option<Resolution>>>,
+    use_degrees: bool,
+}
+
+impl<'a> CellLocator<'a> {
+    pub(crate) fn try_new(
+        coordinates: &'a CoordinateArrays,
+        resolution: HexbinResolution<'a>,
+        use_degrees: bool,
+    ) -> Result<Self, Error> {
+        if coordinates.lat.len() != coordinates.lng.len()
+            || matches!(resolution, HexbinResolution::PerCoordinate(r) if r.len() != coordinates.lat.len())
+        {
+            return Err(Error::LengthMismatch);
+        }
+        let resolutions = match resolution {
+            HexbinResolution::Fixed(_) => None,
+            HexbinResolution::PerCoordinate(r) => Some(r.iter().collect()),
+        };
+        Ok(Self {
+            coordinates,
+            resolution,
+            resolutions,
+            use_degrees,
+        })
+    }
+
+    /// The cell of the coordinate at `pos`. `None` for null coordinates and null resolutions.
+    pub(crate) fn cell(&self, pos: usize) -> Result<Option<CellIndex>, Error> {
+        if self.coordinates.lat.is_null(pos) || self.coordinates.lng.is_null(pos) {
+            return Ok(None);
+        }
+        let r = match (self.resolution, self.resolutions.as_ref()) {
+            (HexbinResolution::Fixed(r), _) => r,
+            (_, Some(resolutions)) => match resolutions[pos] {
+                Some(r) => r,
+                None => return Ok(None),
+            },
+            _ => unreachable!(),
+        };
+        let (lat, lng) = (
+            self.coordinates.lat.value(pos),
+            self.coordinates.lng.value(pos),
+        );
+        let ll = if self.use_degrees {
+            LatLng::new(lat, lng)?
+        } else {
+            LatLng::from_radians(lat, lng)?
+        };
+        Ok(Some(ll.to_cell(r)))
+    }
+}
+
+/// Bin the positions `0..len` by the key returned from `key_fn` and aggregate the `values`
+/// for each key. Positions for which `key_fn` returns `None` are skipped.
+///
+/// Returns the sorted distinct keys, the number of positions for each key and the aggregates
+/// of the `values`.
+pub(crate) fn bin_positions<K, F>(
+    len: usize,
+    values: &[&Float64Array],
+    key_fn: F,
+) -> Result<(Vec<K>, UInt64Array, Vec<HexbinAggregates>), Error>
+where
+    K: Hash + Eq + Ord + Copy + Send,
+    F: Fn(usize) -> Result<Option<K>, Error> + Sync,
+{
+    if values.iter().any(|v| v.len() != len) {
+        return Err(Error::LengthMismatch);
+    }
+
+    let bin = |acc: Result<Bins<K>, Error>, pos: usize| {
+        let mut acc = acc?;
+        if let Some(key) = key_fn(pos)? {
+            acc.add(key, pos, values);
+        }
+        Ok(acc)
+    };
+
+    #[cfg(not(feature = "rayon"))]
+    let bins = (0..len).fold(Ok(Bins::default()), bin)?;
+
+    #[cfg(feature = "rayon")]
+    let bins = (0..len)
+        .into_par_iter()
+        .fold(|| Ok(Bins::default()), bin)
+        .reduce(
+            || Ok(Bins::default()),
+            |a, b| match (a, b) {
+                (Ok(a), Ok(b)) => Ok(a.merge(b)),
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            },
+        )?;
+
+    Ok(bins.finish(values.len()))
+}
+
+struct Bins<K>(HashMap<K, Bin>);
+
+impl<K> Default for Bins<K> {
+    fn default() -> Self {
+        Self(HashMap::default())
+    }
+}
+
+struct Bin {
+    count: u64,
+    values: Vec<ValueAggregate>,
+}
+
+#[derive(Clone, Copy)]
+struct ValueAggregate {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for ValueAggregate {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl ValueAggregate {
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    #[cfg(feature = "rayon")]
+    fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+impl<K> Bins<K>
+where
+    K: Hash + Eq + Ord + Copy,
+{
+    fn add(&mut self, key: K, pos: usize, values: &[&Float64Array]) {
+        let bin = self.0.entry(key).or_insert_with(|| Bin {
+            count: 0,
+            values: vec![ValueAggregate::default(); values.len()],
+        });
+        bin.count += 1;
+        for (agg, value_array) in bin.values.iter_mut().zip(values.iter()) {
+            if value_array.is_valid(pos) {
+                agg.add(value_array.value(pos));
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    fn merge(mut self, mut other: Self) -> Self {
+        if self.0.len() < other.0.len() {
+            std::mem::swap(&mut self, &mut other);
+        }
+        for (key, other_bin) in other.0.into_iter() {
+            match self.0.get_mut(&key) {
+                Some(bin) => {
+                    bin.count += other_bin.count;
+                    for (agg, other_agg) in bin.values.iter_mut().zip(other_bin.values.iter()) {
+                        agg.merge(other_agg);
+                    }
+                }
+                None => {
+                    self.0.insert(key, other_bin);
+                }
+            }
+        }
+        self
+    }
+
+    fn finish(self, num_values: usize) -> (Vec<K>, UInt64Array, Vec<HexbinAggregates>) {
+        let mut bins: Vec<_> = self.0.into_iter().collect();
+        bins.sort_unstable_by_key(|(key, _)| *key);
+
+        let values = (0..num_values)
+            .map(|value_i| {
+                let aggs = || {
+                    bins.iter()
+                        .map(move |(_, bin)| bin.values[value_i])
+                        .map(|agg| (agg.count > 0).then_some(agg))
+                };
+                HexbinAggregates {
+                    sum: aggs().map(|agg| agg.map(|agg| agg.sum)).collect(),
+                    mean: aggs()
+                        .map(|agg| agg.map(|agg| agg.sum / agg.count as f64))
+                        .collect(),
+                    min: aggs().map(|agg| agg.map(|agg| agg.min)).collect(),
+                    max: aggs().map(|agg| agg.map(|agg| agg.max)).collect(),
+                }
+            })
+            .collect();
+
+        let count = bins
+            .iter()
+            .map(|(_, bin)| bin.count)
+            .collect::<Vec<_>>()
+            .into();
+        (
+            bins.into_iter().map(|(key, _)| key).collect(),
+            count,
+            values,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::{CoordinateArrays, HexbinOp};
+    use crate::array::ResolutionArray;
+    use arrow::array::Float64Array;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn hexbin() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![Some(10.3), Some(10.3001), None, Some(23.1)]),
+            lng: Float64Array::from(vec![Some(45.1), Some(45.1001), Some(2.3), Some(2.3)]),
+        };
+        let values = Float64Array::from(vec![Some(1.0), Some(3.0), Some(5.0), None]);
+
+        let hexbins = coordinates
+            .hexbin(Resolution::Seven, &[&values], true)
+            .unwrap();
+        assert_eq!(hexbins.cells.len(), 2);
+        assert_eq!(hexbins.count.values().as_ref(), &[2, 1]);
+
+        let first_cell = LatLng::new(10.3, 45.1).unwrap().to_cell(Resolution::Seven);
+        assert_eq!(hexbins.cells.get(0), Some(first_cell));
+
+        let aggregates = &hexbins.values[0];
+        assert_eq!(
+            aggregates.sum.iter().collect::<Vec<_>>(),
+            vec![Some(4.0), None]
+        );
+        assert_eq!(
+            aggregates.mean.iter().collect::<Vec<_>>(),
+            vec![Some(2.0), None]
+        );
+        assert_eq!(
+            aggregates.min.iter().collect::<Vec<_>>(),
+            vec![Some(1.0), None]
+        );
+        assert_eq!(
+            aggregates.max.iter().collect::<Vec<_>>(),
+            vec![Some(3.0), None]
+        );
+    }
+
+    #[test]
+    fn hexbin_resolution_array() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![10.3, 10.3]),
+            lng: Float64Array::from(vec![45.1, 45.1]),
+        };
+        let resolutions = ResolutionArray::from(vec![Resolution::Five, Resolution::Nine]);
+
+        let hexbins = coordinates.hexbin(&resolutions, &[], true).unwrap();
+        assert_eq!(hexbins.cells.len(), 2);
+        assert!(hexbins.values.is_empty());
+    }
+}
@@ -0,0 +1,402 @@
+use crate::array::{CellIndexArray, DirectedEdgeIndexArray};
+use crate::error::Error;
+use ahash::{HashMap, HashMapExt};
+use arrow::array::{Float64Array, UInt64Array};
+use h3o::{CellIndex, DirectedEdgeIndex, LatLng};
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Hydrological terrain analysis on cells carrying an elevation value.
+///
+/// All operations work on a set of cells of the same resolution together with a
+/// `Float64Array` of the same length holding the elevation of each cell. Cells are
+/// expected to be unique, in case of duplicates the first occurrence is used. Null cells and
+/// cells with a null elevation are treated as not being part of the terrain.
+///
+/// The terrain boundary is formed by all cells having at least one neighbor which is not part
+/// of the terrain - these are the cells water can leave the terrain through.
+pub trait HydrologyOp {
+    /// Raise the elevation of all cells located in depressions to the level of their spill point
+    /// using the priority-flood algorithm.
+    ///
+    /// With an `epsilon` greater than zero, filled areas get a minimal gradient towards their
+    /// spill point, so every cell of the terrain drains to the terrain boundary. With an `epsilon`
+    /// of zero the depressions are filled flat.
+    fn fill_depressions(
+        &self,
+        elevation: &Float64Array,
+        epsilon: f64,
+    ) -> Result<Float64Array, Error>;
+
+    /// The steepest-descent flow direction of each cell as the directed edge to its
+    /// downslope neighbor.
+    ///
+    /// The slope is calculated using the great-circle distance between the cell centroids.
+    /// Cells without a lower neighbor (sinks, flats and outlets at the terrain boundary) get
+    /// a null direction.
+    fn flow_direction(&self, elevation: &Float64Array) -> Result<DirectedEdgeIndexArray, Error>;
+
+    /// The number of cells draining through each cell - including the cell itself.
+    ///
+    /// `directions` must be the flow direction of each cell as returned by `flow_direction`.
+    fn flow_accumulation(&self, directions: &DirectedEdgeIndexArray) -> Result<UInt64Array, Error>;
+
+    /// Delineate the watersheds of the given `pour_points`.
+    ///
+    /// For each cell the pour point it drains to is returned. Cells not draining into any of the
+    /// pour points are set to null. When a pour point is located upstream of another one, its
+    /// watershed is excluded from the watershed of the downstream pour point.
+    fn watersheds(
+        &self,
+        directions: &DirectedEdgeIndexArray,
+        pour_points: &CellIndexArray,
+    ) -> Result<CellIndexArray, Error>;
+}
+
+impl HydrologyOp for CellIndexArray {
+    fn fill_depressions(
+        &self,
+        elevation: &Float64Array,
+        epsilon: f64,
+    ) -> Result<Float64Array, Error> {
+        let terrain = Terrain::new(self, elevation)?;
+        let mut filled: Vec<Option<f64>> = elevation.iter().collect();
+        let mut visited = vec![false; self.len()];
+        let mut queue = BinaryHeap::with_capacity(terrain.positions.len());
+
+        // seed the queue with the boundary of the terrain
+        for (&cell, &pos) in terrain.positions.iter() {
+            if terrain.neighbors(cell).any(|(_, n_pos)| n_pos.is_none()) {
+                visited[pos] = true;
+                queue.push(FloodEntry {
+                    elevation: terrain.elevation(pos),
+                    pos,
+                });
+            }
+        }
+
+        while let Some(entry) = queue.pop() {
+            let cell = terrain.cell(entry.pos);
+            for (_, n_pos) in terrain.neighbors(cell) {
+                let Some(n_pos) = n_pos else {
+                    continue;
+                };
+                if visited[n_pos] {
+                    continue;
+                }
+                visited[n_pos] = true;
+
+                let n_elevation = terrain.elevation(n_pos);
+                let spill_elevation = entry.elevation + epsilon;
+                let n_elevation = if n_elevation <= spill_elevation {
+                    filled[n_pos] = Some(spill_elevation);
+                    spill_elevation
+                } else {
+                    n_elevation
+                };
+                queue.push(FloodEntry {
+                    elevation: n_elevation,
+                    pos: n_pos,
+                });
+            }
+        }
+        Ok(filled.into_iter().collect())
+    }
+
+    fn flow_direction(&self, elevation: &Float64Array) -> Result<DirectedEdgeIndexArray, Error> {
+        let terrain = Terrain::new(self, elevation)?;
+
+        Ok(self
+            .iter()
+            .zip(elevation.iter())
+            .map(|(cell, cell_elevation)| {
+                let (cell, cell_elevation) = (cell?, cell_elevation?);
+                let cell_ll = LatLng::from(cell);
+
+                let mut steepest: Option<(f64, DirectedEdgeIndex)> = None;
+                for (edge, n_pos) in terrain.neighbors(cell) {
+                    let Some(n_pos) = n_pos else {
+                        continue;
+                    };
+                    let drop = cell_elevation - terrain.elevation(n_pos);
+                    if drop <= 0.0 {
+                        continue;
+                    }
+                    let slope = drop / cell_ll.distance_m(LatLng::from(edge.destination()));
+                    if steepest.is_none_or(|(max_slope, _)| slope > max_slope) {
+                        steepest = Some((slope, edge));
+                    }
+                }
+                steepest.map(|(_, edge)| edge)
+            })
+            .collect())
+    }
+
+    fn flow_accumulation(&self, directions: &DirectedEdgeIndexArray) -> Result<UInt64Array, Error> {
+        let network = DrainageNetwork::new(self, directions)?;
+
+        // process the cells in topological order, starting at the ridges
+        let mut accumulation = vec![1u64; self.len()];
+        let mut num_upstream = vec![0usize; self.len()];
+        for downstream in network.downstream.iter().flatten() {
+            num_upstream[*downstream] += 1;
+        }
+        let mut queue: VecDeque<_> = (0..self.len())
+            .filter(|pos| network.is_valid(*pos) && num_upstream[*pos] == 0)
+            .collect();
+
+        while let Some(pos) = queue.pop_front() {
+            if let Some(downstream) = network.downstream[pos] {
+                accumulation[downstream] += accumulation[pos];
+                num_upstream[downstream] -= 1;
+                if num_upstream[downstream] == 0 {
+                    queue.push_back(downstream);
+                }
+            }
+        }
+
+        Ok(accumulation
+            .into_iter()
+            .enumerate()
+            .map(|(pos, acc)| network.is_valid(pos).then_some(acc))
+            .collect())
+    }
+
+    fn watersheds(
+        &self,
+        directions: &DirectedEdgeIndexArray,
+        pour_points: &CellIndexArray,
+    ) -> Result<CellIndexArray, Error> {
+        let network = DrainageNetwork::new(self, directions)?;
+
+        let mut upstream: Vec<Vec<usize>> = vec![vec![]; self.len()];
+        for (pos, downstream) in network.downstream.iter().enumerate() {
+            if let Some(downstream) = downstream {
+                upstream[*downstream].push(pos);
+            }
+        }
+
+        let pour_point_positions: Vec<_> = pour_points
+            .iter()
+            .flatten()
+            .filter_map(|cell| network.positions.get(&cell).copied())
+            .collect();
+
+        let mut watershed: Vec<Option<CellIndex>> = vec![None; self.len()];
+        for pos in pour_point_positions.iter() {
+            watershed[*pos] = self.get(*pos);
+        }
+
+        for pour_point_pos in pour_point_positions {
+            let pour_point = self.get(pour_point_pos);
+            let mut queue = VecDeque::from([pour_point_pos]);
+            while let Some(pos) = queue.pop_front() {
+                for upstream_pos in upstream[pos].iter() {
+                    // stop at pour points further upstream
+                    if watershed[*upstream_pos].is_none() {
+                        watershed[*upstream_pos] = pour_point;
+                        queue.push_back(*upstream_pos);
+                    }
+                }
+            }
+        }
+
+        Ok(watershed.into())
+    }
+}
+
+/// lookup structure for the cells forming the terrain
+struct Terrain<'a> {
+    cells: &'a CellIndexArray,
+    elevation: &'a Float64Array,
+    positions: HashMap<CellIndex, usize>,
+}
+
+impl<'a> Terrain<'a> {
+    fn new(cells: &'a CellIndexArray, elevation: &'a Float64Array) -> Result<Self, Error> {
+        if cells.len() != elevation.len() {
+            return Err(Error::LengthMismatch);
+        }
+
+        let mut positions = HashMap::with_capacity(cells.len());
+        for (pos, (cell, cell_elevation)) in cells.iter().zip(elevation.iter()).enumerate() {
+            if let (Some(cell), Some(_)) = (cell, cell_elevation) {
+                positions.entry(cell).or_insert(pos);
+            }
+        }
+        Ok(Self {
+            cells,
+            elevation,
+            positions,
+        })
+    }
+
+    #[inline]
+    fn cell(&self, pos: usize) -> CellIndex {
+        self.cells.get(pos).expect("terrain cell")
+    }
+
+    #[inline]
+    fn elevation(&self, pos: usize) -> f64 {
+        self.elevation.value(pos)
+    }
+
+    /// The edges to all neighbors of `cell` together with the position of the neighbor
+    /// in the terrain. The position is `None` for neighbors outside of the terrain.
+    fn neighbors(
+        &self,
+        cell: CellIndex,
+    ) -> impl Iterator<Item = (DirectedEdgeIndex, Option<usize>)> + '_ {
+        cell.edges()
+            .map(|edge| (edge, self.positions.get(&edge.destination()).copied()))
+    }
+}
+
+/// cells linked to their downstream neighbors by their flow directions
+struct DrainageNetwork {
+    positions: HashMap<CellIndex, usize>,
+    downstream: Vec<Option<usize>>,
+    valid: Vec<bool>,
+}
+
+impl DrainageNetwork {
+    fn new(cells: &CellIndexArray, directions: &DirectedEdgeIndexArray) -> Result<Self, Error> {
+        if cells.len() != directions.len() {
+            return Err(Error::LengthMismatch);
+        }
+
+        let mut positions = HashMap::with_capacity(cells.len());
+        let mut valid = vec![false; cells.len()];
+        for (pos, cell) in cells.iter().enumerate() {
+            if let Some(cell) = cell {
+                if let Entry::Vacant(e) = positions.entry(cell) {
+                    e.insert(pos);
+                    valid[pos] = true;
+                }
+            }
+        }
+
+        let downstream = cells
+            .iter()
+            .zip(directions.iter())
+            .enumerate()
+            .map(|(pos, (cell, edge))| match (cell, edge) {
+                (Some(cell), Some(edge)) if valid[pos] && edge.origin() == cell => {
+                    positions.get(&edge.destination()).copied()
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self {
+            positions,
+            downstream,
+            valid,
+        })
+    }
+
+    #[inline]
+    fn is_valid(&self, pos: usize) -> bool {
+        self.valid[pos]
+    }
+}
+
+/// entry of the min-heap used by the priority-flood
+struct FloodEntry {
+    elevation: f64,
+    pos: usize,
+}
+
+impl PartialEq for FloodEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FloodEntry {}
+
+impl PartialOrd for FloodEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FloodEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed to turn the max-heap of the std lib into a min-heap
+        other
+            .elevation
+            .total_cmp(&self.elevation)
+            .then_with(|| other.pos.cmp(&self.pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::HydrologyOp;
+    use crate::array::CellIndexArray;
+    use arrow::array::{Array, Float64Array};
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    /// a cone-shaped valley with the center cell being the lowest point
+    fn valley() -> (CellIndexArray, Float64Array, CellIndex) {
+        let center = LatLng::new(47.1, 9.3).unwrap().to_cell(Resolution::Eight);
+        let (cells, elevation): (Vec<_>, Vec<_>) = center
+            .grid_disk_distances::<Vec<_>>(3)
+            .into_iter()
+            .map(|(cell, k)| (cell, 100.0 + 10.0 * k as f64))
+            .unzip();
+        (cells.into(), elevation.into(), center)
+    }
+
+    #[test]
+    fn fill_valley() {
+        let (cells, elevation, _) = valley();
+        let filled = cells.fill_depressions(&elevation, 0.0).unwrap();
+        assert_eq!(filled.len(), cells.len());
+
+        // the valley is filled up to the level of the ring at k=3 forming the boundary
+        for value in filled.iter() {
+            assert_eq!(value, Some(130.0));
+        }
+    }
+
+    #[test]
+    fn flow_into_valley() {
+        let (cells, elevation, center) = valley();
+        let directions = cells.flow_direction(&elevation).unwrap();
+        assert_eq!(directions.len(), cells.len());
+
+        for (cell, edge) in cells.iter().flatten().zip(directions.iter()) {
+            if cell == center {
+                assert!(edge.is_none());
+            } else {
+                let edge = edge.unwrap();
+                assert_eq!(edge.origin(), cell);
+                assert!(
+                    edge.destination().grid_distance(center).unwrap()
+                        < cell.grid_distance(center).unwrap()
+                );
+            }
+        }
+
+        let accumulation = cells.flow_accumulation(&directions).unwrap();
+        let center_pos = cells.iter().position(|c| c == Some(center)).unwrap();
+        assert_eq!(accumulation.value(center_pos), cells.len() as u64);
+        assert_eq!(accumulation.null_count(), 0);
+
+        let watersheds = cells.watersheds(&directions, &vec![center].into()).unwrap();
+        assert!(watersheds.iter().all(|ws| ws == Some(center)));
+    }
+
+    #[test]
+    fn filled_terrain_drains_to_boundary() {
+        let (cells, elevation, _) = valley();
+        let filled = cells.fill_depressions(&elevation, 0.01).unwrap();
+        let directions = cells.flow_direction(&filled).unwrap();
+
+        // only the cells at the boundary do not drain somewhere else
+        assert_eq!(directions.primitive_array().null_count(), 18);
+    }
+}
@@ -1,14 +1,25 @@
+pub mod areal_interpolation;
 pub mod bounding_rect;
 pub mod centroid;
 pub mod change_resolution;
+pub mod cluster;
 pub mod compact;
 pub mod concave_hull;
 pub mod convex_hull;
 pub mod coordinates;
+pub mod dissolve;
+pub mod flow;
 pub mod grid;
+pub mod hexbin;
+pub mod hydrology;
 pub mod localij;
+pub mod radius;
+pub mod spacetime;
 pub mod string;
+pub mod trajectory;
 
+#[allow(unused_imports)]
+pub use areal_interpolation::*;
 #[allow(unused_imports)]
 pub use bounding_rect::*;
 #[allow(unused_imports)]
@@ -16,6 +27,8 @@ pub use centroid::*;
 #[allow(unused_imports)]
 pub use change_resolution::*;
 #[allow(unused_imports)]
+pub use cluster::*;
+#[allow(unused_imports)]
 pub use compact::*;
 #[allow(unused_imports)]
 pub use concave_hull::*;
@@ -24,6 +37,20 @@ pub use convex_hull::*;
 #[allow(unused_imports)]
 pub use coordinates::*;
 #[allow(unused_imports)]
+pub use dissolve::*;
+#[allow(unused_imports)]
+pub use flow::*;
+#[allow(unused_imports)]
 pub use grid::*;
 #[allow(unused_imports)]
+pub use hexbin::*;
+#[allow(unused_imports)]
+pub use hydrology::*;
+#[allow(unused_imports)]
+pub use radius::*;
+#[allow(unused_imports)]
+pub use spacetime::*;
+#[allow(unused_imports)]
 pub use string::*;
+#[allow(unused_imports)]
+pub use trajectory::*;
@@ -0,0 +1,371 @@
+use crate::algorithm::CoordinateArrays;
+use crate::array::from_geo::cell_vecs_to_h3listarray;
+use crate::array::H3ListArray;
+use crate::error::Error;
+use arrow::array::{Array, Float64Array};
+use arrow::error::ArrowError;
+use h3o::geom::ContainmentMode;
+use h3o::{CellIndex, LatLng, Resolution, EARTH_RADIUS_KM};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::f64::consts::PI;
+
+pub trait CellsWithinRadiusOp {
+    /// The cells within the geodesic circle around each coordinate. The radius of each circle
+    /// is taken from the same position in `radius_m` and is given in meters.
+    ///
+    /// The cells are searched using `grid_disk` around the cell containing the center and
+    /// filtered using exact great-circle distances, so the circles keep their geodesic shape
+    /// at all latitudes. The `containment_mode` decides if a cell is part of the circle:
+    ///
+    /// * `ContainsCentroid`: the centroid of the cell is within the circle.
+    /// * `ContainsBoundary`: all vertices of the cell are within the circle.
+    /// * `IntersectsBoundary` and `Covers`: the cell intersects the circle.
+    ///
+    /// The cells of each list are sorted. Rows with a null coordinate or radius result
+    /// in null lists.
+    fn cells_within_radius(
+        &self,
+        radius_m: &Float64Array,
+        resolution: Resolution,
+        containment_mode: ContainmentMode,
+        use_degrees: bool,
+    ) -> Result<H3ListArray<CellIndex>, Error>;
+}
+
+impl CellsWithinRadiusOp for CoordinateArrays {
+    fn cells_within_radius(
+        &self,
+        radius_m: &Float64Array,
+        resolution: Resolution,
+        containment_mode: ContainmentMode,
+        use_degrees: bool,
+    ) -> Result<H3ListArray<CellIndex>, Error> {
+        if self.lat.len() != self.lng.len() || self.lat.len() != radius_m.len() {
+            return Err(Error::LengthMismatch);
+        }
+
+        let circle_cells = |pos: usize| {
+            if self.lat.is_null(pos) || self.lng.is_null(pos) || radius_m.is_null(pos) {
+                return Ok(None);
+            }
+            let (lat, lng) = (self.lat.value(pos), self.lng.value(pos));
+            let center = if use_degrees {
+                LatLng::new(lat, lng)?
+            } else {
+                LatLng::from_radians(lat, lng)?
+            };
+            let circle = GeodesicCircle::try_new(center, radius_m.value(pos))?;
+            Ok(Some(circle.cells(resolution, containment_mode)?))
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let cell_vecs = (0..self.lat.len())
+            .map(circle_cells)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        #[cfg(feature = "rayon")]
+        let cell_vecs = (0..self.lat.len())
+            .into_par_iter()
+            .map(circle_cells)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        cell_vecs_to_h3listarray(cell_vecs)
+    }
+}
+
+/// A circle on the sphere with the radius given as an angle.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GeodesicCircle {
+    center: LatLng,
+    center_xyz: [f64; 3],
+    radius_rads: f64,
+}
+
+impl GeodesicCircle {
+    pub(crate) fn try_new(center: LatLng, radius_m: f64) -> Result<Self, Error> {
+        if !radius_m.is_finite() || radius_m < 0.0 {
+            return Err(
+                ArrowError::InvalidArgumentError(format!("invalid radius: {radius_m}")).into(),
+            );
+        }
+        Ok(Self {
+            center,
+            center_xyz: to_xyz(center),
+            // larger radii cover the whole sphere
+            radius_rads: (radius_m / (EARTH_RADIUS_KM * 1000.0)).min(PI),
+        })
+    }
+
+    pub(crate) fn contains(&self, ll: LatLng) -> bool {
+        self.center.distance_rads(ll) <= self.radius_rads
+    }
+
+    /// `origin` is the cell containing the center at the resolution of `cell`.
+    pub(crate) fn intersects(&self, cell: CellIndex, origin: CellIndex) -> bool {
+        if cell == origin {
+            return true;
+        }
+        let boundary = cell.boundary();
+        let vertexes = boundary.iter().map(|ll| to_xyz(*ll)).collect::<Vec<_>>();
+        vertexes
+            .iter()
+            .zip(vertexes.iter().cycle().skip(1))
+            .any(|(a, b)| arc_distance_rads(&self.center_xyz, a, b) <= self.radius_rads)
+    }
+
+    fn selects(
+        &self,
+        cell: CellIndex,
+        origin: CellIndex,
+        containment_mode: ContainmentMode,
+    ) -> bool {
+        match containment_mode {
+            ContainmentMode::ContainsCentroid => self.contains(LatLng::from(cell)),
+            ContainmentMode::ContainsBoundary => {
+                cell.boundary().iter().all(|ll| self.contains(*ll))
+            }
+            _ => self.intersects(cell, origin),
+        }
+    }
+
+    /// The sorted cells of the circle at `resolution`.
+    pub(crate) fn cells(
+        &self,
+        resolution: Resolution,
+        containment_mode: ContainmentMode,
+    ) -> Result<Vec<CellIndex>, Error> {
+        let origin = self.center.to_cell(resolution);
+
+        // As the initial k is just an estimate, the disk is grown until the cells
+        // intersecting the circle are all within it.
+        let mut k = disk_k(self.radius_rads, resolution)?;
+        loop {
+            let disk = origin.grid_disk_distances::<Vec<_>>(k);
+
+            // the cells intersecting the circle form a connected area around the origin. When
+            // none of them is located in the outermost ring, the disk contains all of them.
+            if disk
+                .iter()
+                .any(|(cell, distance)| *distance == k && self.intersects(*cell, origin))
+            {
+                k = grow_disk_k(k, resolution)?;
+                continue;
+            }
+
+            let mut cells = disk
+                .into_iter()
+                .filter_map(|(cell, _)| {
+                    self.selects(cell, origin, containment_mode).then_some(cell)
+                })
+                .collect::<Vec<_>>();
+            cells.sort_unstable();
+            return Ok(cells);
+        }
+    }
+}
+
+/// The largest disk allocated when searching the cells within a distance. Larger disks
+/// result in an error instead of exhausting the memory.
+const MAX_DISK_CELLS: u64 = 1 << 24;
+
+/// The initial `k` of a disk at `resolution` reaching `reach_rads` from its center.
+///
+/// The distance between the centroids of neighbouring hexagons is sqrt(3) times the
+/// edge length.
+pub(crate) fn disk_k(reach_rads: f64, resolution: Resolution) -> Result<u32, Error> {
+    let spacing_rads = 3.0_f64.sqrt() * resolution.edge_length_rads();
+    let k = (reach_rads.min(PI) / spacing_rads).ceil() + 1.0;
+    check_disk_size(k, resolution)?;
+    Ok(k as u32)
+}
+
+/// Double `k` to grow a disk which turned out to be too small.
+pub(crate) fn grow_disk_k(k: u32, resolution: Resolution) -> Result<u32, Error> {
+    let k = k.checked_mul(2).ok_or_else(|| disk_too_large(resolution))?;
+    check_disk_size(f64::from(k), resolution)?;
+    Ok(k)
+}
+
+fn check_disk_size(k: f64, resolution: Resolution) -> Result<(), Error> {
+    // a disk can not contain more cells than the resolution has
+    let num_cells = (1.0 + 3.0 * k * (k + 1.0)).min(resolution.cell_count() as f64);
+    if num_cells > MAX_DISK_CELLS as f64 || k > f64::from(u32::MAX) {
+        return Err(disk_too_large(resolution));
+    }
+    Ok(())
+}
+
+fn disk_too_large(resolution: Resolution) -> Error {
+    ArrowError::InvalidArgumentError(format!(
+        "the distance covers more than {MAX_DISK_CELLS} cells at resolution {resolution}"
+    ))
+    .into()
+}
+
+pub(crate) fn to_xyz(ll: LatLng) -> [f64; 3] {
+    let (lat, lng) = (ll.lat_radians(), ll.lng_radians());
+    [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+}
+
+pub(crate) fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// The angle between two unit vectors.
+pub(crate) fn angle_rads(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    dot(&cross(a, b), &cross(a, b)).sqrt().atan2(dot(a, b))
+}
+
+/// The angular distance of the point `p` to the shorter great-circle arc from `a` to `b`.
+pub(crate) fn arc_distance_rads(p: &[f64; 3], a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    let n = cross(a, b);
+    let n_len = dot(&n, &n).sqrt();
+    if n_len < f64::EPSILON {
+        return angle_rads(p, a);
+    }
+    let n = [n[0] / n_len, n[1] / n_len, n[2] / n_len];
+    let pn = dot(p, &n);
+
+    // the projection of p onto the plane of the great circle is located on the arc when it
+    // is on the inner side of both endpoints.
+    let c = [p[0] - pn * n[0], p[1] - pn * n[1], p[2] - pn * n[2]];
+    if dot(&cross(a, &c), &n) >= 0.0 && dot(&cross(&c, b), &n) >= 0.0 {
+        pn.abs().min(1.0).asin()
+    } else {
+        angle_rads(p, a).min(angle_rads(p, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::{CellsWithinRadiusOp, CoordinateArrays};
+    use arrow::array::Float64Array;
+    use h3o::geom::ContainmentMode;
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    fn circle_cells(
+        lat: f64,
+        lng: f64,
+        radius_m: f64,
+        containment_mode: ContainmentMode,
+    ) -> Vec<CellIndex> {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![lat]),
+            lng: Float64Array::from(vec![lng]),
+        };
+        let lists = coordinates
+            .cells_within_radius(
+                &Float64Array::from(vec![radius_m]),
+                Resolution::Seven,
+                containment_mode,
+                true,
+            )
+            .unwrap();
+        let cells = lists.iter_arrays().next().unwrap().unwrap().unwrap();
+        cells.iter().flatten().collect()
+    }
+
+    #[test]
+    fn cells_within_radius_high_latitude() {
+        let (lat, lng, radius_m) = (78.2, 15.6, 10_000.0);
+        let center = LatLng::new(lat, lng).unwrap();
+
+        // brute force using a disk far larger than the circle
+        let mut expected = center
+            .to_cell(Resolution::Seven)
+            .grid_disk::<Vec<_>>(30)
+            .into_iter()
+            .filter(|cell| LatLng::from(*cell).distance_m(center) <= radius_m)
+            .collect::<Vec<_>>();
+        expected.sort_unstable();
+
+        let centroid = circle_cells(lat, lng, radius_m, ContainmentMode::ContainsCentroid);
+        assert_eq!(centroid, expected);
+
+        let boundary = circle_cells(lat, lng, radius_m, ContainmentMode::ContainsBoundary);
+        let intersects = circle_cells(lat, lng, radius_m, ContainmentMode::IntersectsBoundary);
+        assert!(!boundary.is_empty());
+        assert!(boundary.len() < centroid.len());
+        assert!(centroid.len() < intersects.len());
+        assert!(boundary.iter().all(|cell| centroid.contains(cell)));
+        assert!(centroid.iter().all(|cell| intersects.contains(cell)));
+        assert!(boundary.iter().all(|cell| cell
+            .boundary()
+            .iter()
+            .all(|ll| ll.distance_m(center) <= radius_m)));
+    }
+
+    #[test]
+    fn cells_within_radius_small_and_null() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![Some(10.0), None, Some(10.0)]),
+            lng: Float64Array::from(vec![Some(20.0), Some(20.0), Some(20.0)]),
+        };
+        let lists = coordinates
+            .cells_within_radius(
+                &Float64Array::from(vec![Some(1.0), Some(1.0), None]),
+                Resolution::Five,
+                ContainmentMode::IntersectsBoundary,
+                true,
+            )
+            .unwrap();
+        let lists = lists.iter_arrays().collect::<Vec<_>>();
+        assert_eq!(lists.len(), 3);
+
+        // a tiny circle is still covered by the cell containing its center
+        let cells = lists[0].as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(cells.len(), 1);
+        assert_eq!(
+            cells.get(0),
+            Some(LatLng::new(10.0, 20.0).unwrap().to_cell(Resolution::Five))
+        );
+        assert!(lists[1].is_none());
+        assert!(lists[2].is_none());
+    }
+
+    #[test]
+    fn cells_within_radius_invalid_radius() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![10.0]),
+            lng: Float64Array::from(vec![20.0]),
+        };
+        assert!(coordinates
+            .cells_within_radius(
+                &Float64Array::from(vec![-1.0]),
+                Resolution::Five,
+                ContainmentMode::ContainsCentroid,
+                true,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn cells_within_radius_huge_radius() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![10.0]),
+            lng: Float64Array::from(vec![20.0]),
+        };
+        let radius_m = Float64Array::from(vec![1e9]);
+
+        // the circle covers the whole sphere
+        let lists = coordinates
+            .cells_within_radius(&radius_m, Resolution::One, ContainmentMode::Covers, true)
+            .unwrap();
+        let cells = lists.iter_arrays().next().unwrap().unwrap().unwrap();
+        assert_eq!(cells.len() as u64, Resolution::One.cell_count());
+
+        // too many cells to allocate
+        assert!(coordinates
+            .cells_within_radius(&radius_m, Resolution::Twelve, ContainmentMode::Covers, true)
+            .is_err());
+    }
+}
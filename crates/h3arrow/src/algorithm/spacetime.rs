@@ -0,0 +1,329 @@
+use crate::algorithm::hexbin::{bin_positions, CellLocator};
+use crate::algorithm::{CoordinateArrays, HexbinAggregates, HexbinResolution};
+use crate::array::CellIndexArray;
+use crate::error::Error;
+use arrow::array::temporal_conversions::as_datetime;
+use arrow::array::{
+    Array, ArrayRef, FixedSizeBinaryArray, Float64Array, PrimitiveArray, StructArray, UInt64Array,
+};
+use arrow::datatypes::{ArrowTimestampType, DataType, Field, TimeUnit};
+use arrow::error::ArrowError;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use h3o::CellIndex;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Calendar units for time buckets of varying length.
+///
+/// Calendar buckets are computed in UTC, the timezone of the timestamp array is ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarUnit {
+    Day,
+    /// ISO weeks, starting on monday
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+/// The time bucket to bin timestamps into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeBucket {
+    /// Buckets of a fixed duration, aligned to the unix epoch.
+    Fixed(Duration),
+
+    /// Buckets of calendar units.
+    Calendar(CalendarUnit),
+}
+
+impl From<Duration> for TimeBucket {
+    fn from(duration: Duration) -> Self {
+        Self::Fixed(duration)
+    }
+}
+
+impl From<CalendarUnit> for TimeBucket {
+    fn from(unit: CalendarUnit) -> Self {
+        Self::Calendar(unit)
+    }
+}
+
+impl TimeBucket {
+    /// Returns a function computing the start of the bucket a timestamp of type `T` falls into.
+    fn bucket_start_fn<T: ArrowTimestampType>(
+        self,
+    ) -> Result<impl Fn(i64) -> Result<i64, Error> + Sync, Error> {
+        let bucket_length = match self {
+            Self::Fixed(duration) => {
+                let length = match T::UNIT {
+                    TimeUnit::Second => duration.as_secs() as i128,
+                    TimeUnit::Millisecond => duration.as_millis() as i128,
+                    TimeUnit::Microsecond => duration.as_micros() as i128,
+                    TimeUnit::Nanosecond => duration.as_nanos() as i128,
+                };
+                let length = i64::try_from(length).unwrap_or(i64::MAX);
+                if length <= 0 {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "time bucket duration {:?} is shorter than the unit {:?} of the timestamps",
+                        duration,
+                        T::UNIT
+                    ))
+                    .into());
+                }
+                Some(length)
+            }
+            Self::Calendar(_) => None,
+        };
+
+        Ok(move |timestamp: i64| {
+            match (self, bucket_length) {
+                (_, Some(length)) => timestamp.div_euclid(length).checked_mul(length),
+                (Self::Calendar(unit), None) => as_datetime::<T>(timestamp)
+                    .and_then(|dt| calendar_bucket_start(dt, unit))
+                    .and_then(T::make_value),
+                _ => unreachable!(),
+            }
+            .ok_or_else(|| {
+                ArrowError::ComputeError(format!(
+                    "timestamp {} is out of the supported range",
+                    timestamp
+                ))
+                .into()
+            })
+        })
+    }
+}
+
+fn calendar_bucket_start(dt: NaiveDateTime, unit: CalendarUnit) -> Option<NaiveDateTime> {
+    let date = dt.date();
+    let start = match unit {
+        CalendarUnit::Day => Some(date),
+        CalendarUnit::Week => date.checked_sub_days(chrono::Days::new(
+            date.weekday().num_days_from_monday() as u64,
+        )),
+        CalendarUnit::Month => NaiveDate::from_ymd_opt(date.year(), date.month(), 1),
+        CalendarUnit::Quarter => {
+            NaiveDate::from_ymd_opt(date.year(), ((date.month() - 1) / 3) * 3 + 1, 1)
+        }
+        CalendarUnit::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1),
+    };
+    start.map(|d| d.and_time(chrono::NaiveTime::MIN))
+}
+
+/// Builds a key of a cell and a time bucket which sorts in the same order as
+/// the tuple `(cell, bucket_start)`.
+pub fn spacetime_key(cell: CellIndex, bucket_start: i64) -> u128 {
+    ((u64::from(cell) as u128) << 64) | ((bucket_start as u64) ^ (1 << 63)) as u128
+}
+
+pub struct SpaceTimeBins<T: ArrowTimestampType> {
+    /// the cells of the bins. Bins are sorted by cell and bucket start.
+    pub cells: CellIndexArray,
+
+    /// the start of the time bucket of the bins, using the unit and timezone of the input timestamps
+    pub bucket_start: PrimitiveArray<T>,
+
+    /// the number of coordinates located in each bin
+    pub count: UInt64Array,
+
+    /// the aggregates of the value columns in the order the value columns were passed in
+    pub values: Vec<HexbinAggregates>,
+}
+
+impl<T: ArrowTimestampType> SpaceTimeBins<T> {
+    /// The combined keys of the bins as created by [`spacetime_key`], stored as
+    /// 16 big-endian bytes. The bytewise order of the keys matches the order of the bins.
+    pub fn keys(&self) -> FixedSizeBinaryArray {
+        FixedSizeBinaryArray::try_from_iter(
+            self.cells
+                .iter()
+                .zip(self.bucket_start.values().iter())
+                .map(|(cell, bucket_start)| {
+                    // bins never contain null cells
+                    spacetime_key(cell.expect("non-null cell"), *bucket_start).to_be_bytes()
+                }),
+        )
+        .expect("fixed size keys")
+    }
+
+    /// The keys of the bins as a struct of the `cell` and the `bucket_start`.
+    pub fn struct_keys(&self) -> StructArray {
+        let bucket_start = self.bucket_start.clone();
+        StructArray::from(vec![
+            (
+                Arc::new(Field::new("cell", DataType::UInt64, false)),
+                Arc::new(UInt64Array::from(self.cells.clone())) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new(
+                    "bucket_start",
+                    bucket_start.data_type().clone(),
+                    false,
+                )),
+                Arc::new(bucket_start) as ArrayRef,
+            ),
+        ])
+    }
+}
+
+pub trait SpaceTimeBinOp {
+    /// Bin the coordinates and their timestamps into cells and time buckets and aggregate the
+    /// optional `values` for each bin.
+    ///
+    /// All `values` arrays and the `timestamps` must have the same length as the coordinate arrays.
+    /// Coordinates with a null latitude, longitude or timestamp are skipped.
+    fn spacetime_bin<'a, T: ArrowTimestampType>(
+        &self,
+        timestamps: &PrimitiveArray<T>,
+        resolution: impl Into<HexbinResolution<'a>>,
+        bucket: impl Into<TimeBucket>,
+        values: &[&Float64Array],
+        use_degrees: bool,
+    ) -> Result<SpaceTimeBins<T>, Error>;
+}
+
+impl SpaceTimeBinOp for CoordinateArrays {
+    fn spacetime_bin<'a, T: ArrowTimestampType>(
+        &self,
+        timestamps: &PrimitiveArray<T>,
+        resolution: impl Into<HexbinResolution<'a>>,
+        bucket: impl Into<TimeBucket>,
+        values: &[&Float64Array],
+        use_degrees: bool,
+    ) -> Result<SpaceTimeBins<T>, Error> {
+        if timestamps.len() != self.lat.len() {
+            return Err(Error::LengthMismatch);
+        }
+        let locator = CellLocator::try_new(self, resolution.into(), use_degrees)?;
+        let bucket_start = bucket.into().bucket_start_fn::<T>()?;
+
+        let (keys, count, values) = bin_positions(self.lat.len(), values, |pos| {
+            if timestamps.is_null(pos) {
+                return Ok(None);
+            }
+            match locator.cell(pos)? {
+                Some(cell) => Ok(Some((cell, bucket_start(timestamps.value(pos))?))),
+                None => Ok(None),
+            }
+        })?;
+
+        Ok(SpaceTimeBins {
+            cells: keys.iter().map(|(cell, _)| *cell).collect(),
+            bucket_start: PrimitiveArray::<T>::from_iter_values(
+                keys.iter().map(|(_, bucket_start)| *bucket_start),
+            )
+            .with_timezone_opt(timestamps.timezone().map(|tz| tz.to_string())),
+            count,
+            values,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::{spacetime_key, CalendarUnit, CoordinateArrays, SpaceTimeBinOp};
+    use arrow::array::{Array, Float64Array, TimestampSecondArray};
+    use h3o::{LatLng, Resolution};
+    use std::time::Duration;
+
+    // 2024-01-31T12:00:00Z, 2024-02-01T08:00:00Z, 2024-02-01T09:30:00Z
+    const TIMESTAMPS: [i64; 3] = [1706702400, 1706774400, 1706779800];
+
+    fn coordinates() -> CoordinateArrays {
+        CoordinateArrays {
+            lat: Float64Array::from(vec![10.3, 10.3, 10.3]),
+            lng: Float64Array::from(vec![45.1, 45.1, 45.1]),
+        }
+    }
+
+    #[test]
+    fn spacetime_bin_fixed() {
+        let timestamps = TimestampSecondArray::from(TIMESTAMPS.to_vec()).with_timezone("UTC");
+        let values = Float64Array::from(vec![1.0, 2.0, 4.0]);
+
+        let bins = coordinates()
+            .spacetime_bin(
+                &timestamps,
+                Resolution::Seven,
+                Duration::from_secs(3600),
+                &[&values],
+                true,
+            )
+            .unwrap();
+        assert_eq!(bins.cells.len(), 3);
+        assert_eq!(
+            bins.bucket_start.values().as_ref(),
+            &[1706702400, 1706774400, 1706778000]
+        );
+        assert_eq!(bins.bucket_start.timezone(), Some("UTC"));
+        assert_eq!(bins.count.values().as_ref(), &[1, 1, 1]);
+
+        let keys = bins.keys();
+        assert_eq!(keys.len(), 3);
+        assert!(keys.value(0) < keys.value(1));
+        assert!(keys.value(1) < keys.value(2));
+        assert_eq!(bins.struct_keys().num_columns(), 2);
+    }
+
+    #[test]
+    fn spacetime_bin_out_of_range() {
+        let timestamps = TimestampSecondArray::from(vec![i64::MIN]);
+
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![10.3]),
+            lng: Float64Array::from(vec![45.1]),
+        };
+
+        let result = coordinates.spacetime_bin(
+            &timestamps,
+            Resolution::Seven,
+            Duration::from_secs(7),
+            &[],
+            true,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn spacetime_bin_calendar() {
+        let timestamps = TimestampSecondArray::from(TIMESTAMPS.to_vec());
+        let values = Float64Array::from(vec![1.0, 2.0, 4.0]);
+
+        let bins = coordinates()
+            .spacetime_bin(
+                &timestamps,
+                Resolution::Seven,
+                CalendarUnit::Month,
+                &[&values],
+                true,
+            )
+            .unwrap();
+        // 2024-01-01, 2024-02-01
+        assert_eq!(
+            bins.bucket_start.values().as_ref(),
+            &[1704067200, 1706745600]
+        );
+        assert_eq!(bins.count.values().as_ref(), &[1, 2]);
+        assert_eq!(bins.values[0].sum.values().as_ref(), &[1.0, 6.0]);
+
+        let bins = coordinates()
+            .spacetime_bin(
+                &timestamps,
+                Resolution::Seven,
+                CalendarUnit::Week,
+                &[],
+                true,
+            )
+            .unwrap();
+        // monday, 2024-01-29
+        assert_eq!(bins.bucket_start.values().as_ref(), &[1706486400]);
+        assert_eq!(bins.count.values().as_ref(), &[3]);
+    }
+
+    #[test]
+    fn spacetime_key_order() {
+        let cell = LatLng::new(10.3, 45.1).unwrap().to_cell(Resolution::Seven);
+        assert!(spacetime_key(cell, -10) < spacetime_key(cell, 10));
+        assert!(spacetime_key(cell, i64::MAX) < spacetime_key(cell.succ().unwrap(), i64::MIN));
+    }
+}
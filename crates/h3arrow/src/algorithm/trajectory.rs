@@ -0,0 +1,344 @@
+use crate::algorithm::CoordinateArrays;
+use crate::array::{genericlistarray_to_h3listarray_unvalidated, CellIndexArray, H3ListArray};
+use crate::error::Error;
+use arrow::array::{Array, ArrayRef, GenericListArray, PrimitiveArray, StructArray, UInt64Array};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::partition;
+use arrow::datatypes::{ArrowTimestampType, DataType, Field, Fields};
+use h3o::{CellIndex, LatLng, Resolution};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Run-length-encoded cell sequences of tracks.
+///
+/// The runs of all tracks are stored in consecutive arrays, the `offsets` delimit the
+/// runs of the individual tracks.
+pub struct CellSequences<T: ArrowTimestampType> {
+    /// offsets of the runs of each track
+    pub offsets: OffsetBuffer<i64>,
+
+    /// the position of the first point of each track in the input arrays
+    pub track_start: UInt64Array,
+
+    /// the cell of each run
+    pub cells: CellIndexArray,
+
+    /// the timestamp of the first point of each run
+    pub entry_time: PrimitiveArray<T>,
+
+    /// the timestamp of the last point of each run
+    pub exit_time: PrimitiveArray<T>,
+
+    /// the number of points within each run. Cells added to fill gaps have a count of 0.
+    pub point_count: UInt64Array,
+}
+
+impl<T: ArrowTimestampType> CellSequences<T> {
+    pub fn num_tracks(&self) -> usize {
+        self.track_start.len()
+    }
+
+    /// The start position of the track of each run, to be used to `take` the track ids
+    /// of the input for each run.
+    pub fn run_track_start(&self) -> UInt64Array {
+        self.offsets
+            .windows(2)
+            .zip(self.track_start.values().iter())
+            .flat_map(|(window, track_start)| {
+                std::iter::repeat_n(*track_start, (window[1] - window[0]) as usize)
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// The cells of the runs as a list array with one list per track.
+    pub fn to_cell_lists(&self) -> Result<H3ListArray<CellIndex>, Error> {
+        genericlistarray_to_h3listarray_unvalidated(GenericListArray::new(
+            Arc::new(Field::new("item", DataType::UInt64, true)),
+            self.offsets.clone(),
+            Arc::new(UInt64Array::from(self.cells.clone())),
+            None,
+        ))
+    }
+
+    /// The runs of all tracks as a struct array with the fields `cell`, `entry_time`, `exit_time`
+    /// and `point_count`.
+    pub fn to_struct_array(&self) -> StructArray {
+        StructArray::new(
+            self.fields(),
+            vec![
+                Arc::new(UInt64Array::from(self.cells.clone())) as ArrayRef,
+                Arc::new(self.entry_time.clone()),
+                Arc::new(self.exit_time.clone()),
+                Arc::new(self.point_count.clone()),
+            ],
+            None,
+        )
+    }
+
+    /// The runs as a list array of structs with one list per track.
+    pub fn to_list_array(&self) -> GenericListArray<i64> {
+        GenericListArray::new(
+            Arc::new(Field::new_struct("item", self.fields(), false)),
+            self.offsets.clone(),
+            Arc::new(self.to_struct_array()),
+            None,
+        )
+    }
+
+    fn fields(&self) -> Fields {
+        Fields::from(vec![
+            Field::new("cell", DataType::UInt64, false),
+            Field::new("entry_time", self.entry_time.data_type().clone(), false),
+            Field::new("exit_time", self.exit_time.data_type().clone(), false),
+            Field::new("point_count", DataType::UInt64, false),
+        ])
+    }
+}
+
+pub trait TrajectoryOp {
+    /// Convert ordered tracks of coordinates and timestamps to run-length-encoded cell sequences.
+    ///
+    /// The points of a track must be ordered by time. Consecutive rows with equal values in
+    /// `track_ids` form a track, without `track_ids` all points are treated as a single track.
+    /// Points with a null coordinate or timestamp are skipped.
+    ///
+    /// Consecutive points within the same cell are collapsed into a single run. With `fill_gaps`
+    /// enabled, the grid path between consecutive non-neighboring cells is added to the
+    /// sequence. The entry and exit times of these cells are interpolated linearly. Gaps for which no
+    /// grid path can be found - for example across pentagons - are left unfilled.
+    fn to_cell_sequences<T: ArrowTimestampType>(
+        &self,
+        timestamps: &PrimitiveArray<T>,
+        track_ids: Option<&ArrayRef>,
+        resolution: Resolution,
+        fill_gaps: bool,
+        use_degrees: bool,
+    ) -> Result<CellSequences<T>, Error>;
+}
+
+impl TrajectoryOp for CoordinateArrays {
+    fn to_cell_sequences<T: ArrowTimestampType>(
+        &self,
+        timestamps: &PrimitiveArray<T>,
+        track_ids: Option<&ArrayRef>,
+        resolution: Resolution,
+        fill_gaps: bool,
+        use_degrees: bool,
+    ) -> Result<CellSequences<T>, Error> {
+        if self.lat.len() != self.lng.len()
+            || self.lat.len() != timestamps.len()
+            || track_ids.is_some_and(|ids| ids.len() != self.lat.len())
+        {
+            return Err(Error::LengthMismatch);
+        }
+
+        let tracks = match track_ids {
+            Some(track_ids) => partition(std::slice::from_ref(track_ids))?.ranges(),
+            None if self.lat.is_empty() => vec![],
+            None => vec![Range {
+                start: 0,
+                end: self.lat.len(),
+            }],
+        };
+
+        let track_runs = |track: &Range<usize>| {
+            track_to_runs(
+                self,
+                timestamps,
+                track.clone(),
+                resolution,
+                fill_gaps,
+                use_degrees,
+            )
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let runs = tracks
+            .iter()
+            .map(track_runs)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        #[cfg(feature = "rayon")]
+        let runs = tracks
+            .as_slice()
+            .into_par_iter()
+            .map(track_runs)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let offsets = OffsetBuffer::from_lengths(runs.iter().map(Vec::len));
+        let runs = runs.into_iter().flatten().collect::<Vec<_>>();
+        Ok(CellSequences {
+            offsets,
+            track_start: tracks
+                .iter()
+                .map(|track| track.start as u64)
+                .collect::<Vec<_>>()
+                .into(),
+            cells: runs.iter().map(|run| run.cell).collect(),
+            entry_time: PrimitiveArray::<T>::from_iter_values(runs.iter().map(|run| run.entry))
+                .with_timezone_opt(timestamps.timezone().map(|tz| tz.to_string())),
+            exit_time: PrimitiveArray::<T>::from_iter_values(runs.iter().map(|run| run.exit))
+                .with_timezone_opt(timestamps.timezone().map(|tz| tz.to_string())),
+            point_count: runs
+                .iter()
+                .map(|run| run.point_count)
+                .collect::<Vec<_>>()
+                .into(),
+        })
+    }
+}
+
+struct Run {
+    cell: CellIndex,
+    entry: i64,
+    exit: i64,
+    point_count: u64,
+}
+
+fn track_to_runs<T: ArrowTimestampType>(
+    coordinates: &CoordinateArrays,
+    timestamps: &PrimitiveArray<T>,
+    track: Range<usize>,
+    resolution: Resolution,
+    fill_gaps: bool,
+    use_degrees: bool,
+) -> Result<Vec<Run>, Error> {
+    let mut runs: Vec<Run> = Vec::new();
+    for pos in track {
+        if coordinates.lat.is_null(pos) || coordinates.lng.is_null(pos) || timestamps.is_null(pos) {
+            continue;
+        }
+        let (lat, lng) = (coordinates.lat.value(pos), coordinates.lng.value(pos));
+        let ll = if use_degrees {
+            LatLng::new(lat, lng)?
+        } else {
+            LatLng::from_radians(lat, lng)?
+        };
+        let cell = ll.to_cell(resolution);
+        let timestamp = timestamps.value(pos);
+
+        if let Some(last) = runs.last_mut() {
+            if last.cell == cell {
+                last.exit = timestamp;
+                last.point_count += 1;
+                continue;
+            }
+            if fill_gaps {
+                let (from, departure) = (last.cell, last.exit);
+                fill_gap(&mut runs, from, departure, cell, timestamp);
+            }
+        }
+        runs.push(Run {
+            cell,
+            entry: timestamp,
+            exit: timestamp,
+            point_count: 1,
+        });
+    }
+    Ok(runs)
+}
+
+/// Add the cells of the grid path between `from` and `to` - both exclusive - to `runs`.
+fn fill_gap(runs: &mut Vec<Run>, from: CellIndex, departure: i64, to: CellIndex, arrival: i64) {
+    if from.is_neighbor_with(to).unwrap_or(false) {
+        return;
+    }
+    let Some(path) = from
+        .grid_path_cells(to)
+        .ok()
+        .and_then(|path| path.collect::<Result<Vec<_>, _>>().ok())
+    else {
+        return;
+    };
+    if path.len() < 3 {
+        return;
+    }
+    let steps = (path.len() - 1) as i128;
+    let duration = arrival as i128 - departure as i128;
+    for (step, cell) in path[1..path.len() - 1].iter().enumerate() {
+        let passage = (departure as i128 + duration * (step as i128 + 1) / steps) as i64;
+        runs.push(Run {
+            cell: *cell,
+            entry: passage,
+            exit: passage,
+            point_count: 0,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::{CoordinateArrays, TrajectoryOp};
+    use arrow::array::{Array, ArrayRef, Float64Array, StringArray, TimestampSecondArray};
+    use h3o::{LatLng, Resolution};
+    use std::sync::Arc;
+
+    #[test]
+    fn to_cell_sequences() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![10.3, 10.3, 10.3, 12.0, 20.0]),
+            lng: Float64Array::from(vec![45.1, 45.1, 45.1, 46.0, 10.0]),
+        };
+        let timestamps = TimestampSecondArray::from(vec![0, 10, 20, 100, 0]);
+        let track_ids: ArrayRef = Arc::new(StringArray::from(vec!["a", "a", "a", "a", "b"]));
+
+        let sequences = coordinates
+            .to_cell_sequences(&timestamps, Some(&track_ids), Resolution::Five, false, true)
+            .unwrap();
+        assert_eq!(sequences.num_tracks(), 2);
+        assert_eq!(sequences.offsets.as_ref(), &[0, 2, 3]);
+        assert_eq!(sequences.track_start.values().as_ref(), &[0, 4]);
+        assert_eq!(sequences.cells.len(), 3);
+        assert_eq!(
+            sequences.cells.get(0),
+            Some(LatLng::new(10.3, 45.1).unwrap().to_cell(Resolution::Five))
+        );
+        assert_eq!(sequences.entry_time.values().as_ref(), &[0, 100, 0]);
+        assert_eq!(sequences.exit_time.values().as_ref(), &[20, 100, 0]);
+        assert_eq!(sequences.point_count.values().as_ref(), &[3, 1, 1]);
+        assert_eq!(sequences.run_track_start().values().as_ref(), &[0, 0, 4]);
+
+        let list = sequences.to_list_array();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.value(0).len(), 2);
+    }
+
+    #[test]
+    fn to_cell_sequences_fill_gaps() {
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![10.3, 12.0]),
+            lng: Float64Array::from(vec![45.1, 46.0]),
+        };
+        let timestamps = TimestampSecondArray::from(vec![0, 100]);
+
+        let sequences = coordinates
+            .to_cell_sequences(&timestamps, None, Resolution::Five, true, true)
+            .unwrap();
+        let first = LatLng::new(10.3, 45.1).unwrap().to_cell(Resolution::Five);
+        let last = LatLng::new(12.0, 46.0).unwrap().to_cell(Resolution::Five);
+        let distance = first.grid_distance(last).unwrap() as usize;
+
+        assert_eq!(sequences.num_tracks(), 1);
+        assert_eq!(sequences.cells.len(), distance + 1);
+        assert_eq!(sequences.cells.get(distance), Some(last));
+        assert!(sequences
+            .point_count
+            .values()
+            .iter()
+            .skip(1)
+            .take(distance - 1)
+            .all(|count| *count == 0));
+
+        let entry_times = sequences.entry_time.values();
+        assert!(entry_times.windows(2).all(|w| w[0] <= w[1]));
+
+        // consecutive cells are neighbors
+        let cells = sequences.cells.iter().flatten().collect::<Vec<_>>();
+        assert!(cells
+            .windows(2)
+            .all(|w| w[0].is_neighbor_with(w[1]).unwrap()));
+    }
+}
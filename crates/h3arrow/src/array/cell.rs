@@ -1,6 +1,7 @@
 use arrow::array::{Float64Array, UInt64Array};
-use h3o::{CellIndex, Resolution};
+use h3o::{CellIndex, LatLng, Resolution};
 
+use crate::algorithm::CoordinateArrays;
 use crate::array::{CellIndexArray, H3ListArray, H3ListArrayBuilder, ResolutionArray};
 use crate::error::Error;
 
@@ -29,6 +30,123 @@ impl CellIndexArray {
             .collect()
     }
 
+    /// Great-circle distance in radians between the centroids of the cells at the same
+    /// positions in `self` and `other`.
+    pub fn distance_rads(&self, other: &Self) -> Result<Float64Array, Error> {
+        self.paired_distance(other, LatLng::distance_rads)
+    }
+
+    /// Great-circle distance in kilometers between the centroids of the cells at the same
+    /// positions in `self` and `other`.
+    pub fn distance_km(&self, other: &Self) -> Result<Float64Array, Error> {
+        self.paired_distance(other, LatLng::distance_km)
+    }
+
+    /// Great-circle distance in meters between the centroids of the cells at the same
+    /// positions in `self` and `other`.
+    pub fn distance_m(&self, other: &Self) -> Result<Float64Array, Error> {
+        self.paired_distance(other, LatLng::distance_m)
+    }
+
+    /// Great-circle distance in radians between the centroid of each cell and the coordinate
+    /// at the same position.
+    pub fn distance_to_coordinates_rads(
+        &self,
+        coordinates: &CoordinateArrays,
+        use_degrees: bool,
+    ) -> Result<Float64Array, Error> {
+        self.coordinates_distance(coordinates, use_degrees, LatLng::distance_rads)
+    }
+
+    /// Great-circle distance in kilometers between the centroid of each cell and the coordinate
+    /// at the same position.
+    pub fn distance_to_coordinates_km(
+        &self,
+        coordinates: &CoordinateArrays,
+        use_degrees: bool,
+    ) -> Result<Float64Array, Error> {
+        self.coordinates_distance(coordinates, use_degrees, LatLng::distance_km)
+    }
+
+    /// Great-circle distance in meters between the centroid of each cell and the coordinate
+    /// at the same position.
+    pub fn distance_to_coordinates_m(
+        &self,
+        coordinates: &CoordinateArrays,
+        use_degrees: bool,
+    ) -> Result<Float64Array, Error> {
+        self.coordinates_distance(coordinates, use_degrees, LatLng::distance_m)
+    }
+
+    /// Great-circle distance in radians between the centroid of each cell and `point`.
+    pub fn distance_to_point_rads(&self, point: LatLng) -> Float64Array {
+        self.point_distance(point, LatLng::distance_rads)
+    }
+
+    /// Great-circle distance in kilometers between the centroid of each cell and `point`.
+    pub fn distance_to_point_km(&self, point: LatLng) -> Float64Array {
+        self.point_distance(point, LatLng::distance_km)
+    }
+
+    /// Great-circle distance in meters between the centroid of each cell and `point`.
+    pub fn distance_to_point_m(&self, point: LatLng) -> Float64Array {
+        self.point_distance(point, LatLng::distance_m)
+    }
+
+    fn paired_distance<F>(&self, other: &Self, distance: F) -> Result<Float64Array, Error>
+    where
+        F: Fn(LatLng, LatLng) -> f64,
+    {
+        if self.len() != other.len() {
+            return Err(Error::LengthMismatch);
+        }
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|cells| match cells {
+                (Some(a), Some(b)) => Some(distance(LatLng::from(a), LatLng::from(b))),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn coordinates_distance<F>(
+        &self,
+        coordinates: &CoordinateArrays,
+        use_degrees: bool,
+        distance: F,
+    ) -> Result<Float64Array, Error>
+    where
+        F: Fn(LatLng, LatLng) -> f64,
+    {
+        if self.len() != coordinates.lat.len() || self.len() != coordinates.lng.len() {
+            return Err(Error::LengthMismatch);
+        }
+        self.iter()
+            .zip(coordinates.lat.iter().zip(coordinates.lng.iter()))
+            .map(|values| match values {
+                (Some(cell), (Some(lat), Some(lng))) => {
+                    let ll = if use_degrees {
+                        LatLng::new(lat, lng)?
+                    } else {
+                        LatLng::from_radians(lat, lng)?
+                    };
+                    Ok(Some(distance(LatLng::from(cell), ll)))
+                }
+                _ => Ok(None),
+            })
+            .collect()
+    }
+
+    fn point_distance<F>(&self, point: LatLng, distance: F) -> Float64Array
+    where
+        F: Fn(LatLng, LatLng) -> f64,
+    {
+        self.iter()
+            .map(|cell| cell.map(|cell| distance(LatLng::from(cell), point)))
+            .collect()
+    }
+
     pub fn parent(&self, resolution: Resolution) -> Self {
         self.iter()
             .map(|cell| cell.and_then(|cell| cell.parent(resolution)))
@@ -58,8 +176,10 @@ impl CellIndexArray {
 
 #[cfg(test)]
 mod test {
+    use arrow::array::{Array, Float64Array};
     use h3o::{LatLng, Resolution};
 
+    use crate::algorithm::CoordinateArrays;
     use crate::array::CellIndexArray;
 
     #[test]
@@ -107,4 +227,42 @@ mod test {
             assert_eq!(arr.iter().next().flatten(), child.parent(Resolution::Five));
         }
     }
+
+    #[test]
+    fn distance() {
+        let a = LatLng::new(52.5, 13.4).unwrap();
+        let b = LatLng::new(48.9, 2.35).unwrap();
+        let cells: CellIndexArray = vec![
+            Some(a.to_cell(Resolution::Nine)),
+            None,
+            Some(b.to_cell(Resolution::Nine)),
+        ]
+        .into();
+        let others: CellIndexArray = vec![
+            Some(b.to_cell(Resolution::Nine)),
+            Some(b.to_cell(Resolution::Nine)),
+            Some(b.to_cell(Resolution::Nine)),
+        ]
+        .into();
+
+        let distance_km = cells.distance_km(&others).unwrap();
+        assert_eq!(distance_km.null_count(), 1);
+        assert!((distance_km.value(0) - a.distance_km(b)).abs() < 0.5);
+        assert!(distance_km.value(2) < 1e-9);
+        let distance_m = cells.distance_m(&others).unwrap();
+        assert!((distance_m.value(0) - distance_km.value(0) * 1000.0).abs() < 1e-6);
+        assert!(cells.distance_m(&CellIndexArray::new_null(1)).is_err());
+
+        let to_point = cells.distance_to_point_rads(b);
+        assert!((to_point.value(0) - cells.distance_rads(&others).unwrap().value(0)).abs() < 1e-4);
+
+        let coordinates = CoordinateArrays {
+            lat: Float64Array::from(vec![Some(b.lat()), Some(b.lat()), None]),
+            lng: Float64Array::from(vec![b.lng(), b.lng(), b.lng()]),
+        };
+        let to_coordinates = cells.distance_to_coordinates_m(&coordinates, true).unwrap();
+        assert!((to_coordinates.value(0) - cells.distance_to_point_m(b).value(0)).abs() < 1e-6);
+        assert!(to_coordinates.is_null(1));
+        assert!(to_coordinates.is_null(2));
+    }
 }
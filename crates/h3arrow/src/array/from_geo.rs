@@ -1,20 +1,59 @@
-use arrow::array::OffsetSizeTrait;
-use geo::HasDimensions;
+use ahash::{HashMap, HashSet};
+use arrow::array::{BooleanArray, Float64Array, OffsetSizeTrait, UInt64Array};
+use arrow::error::ArrowError;
+use geo::orient::Direction;
+use geo::{BooleanOps, GeodesicArea, HasDimensions, Intersects, Orient, PreparedGeometry, Relate};
 use geo_types::*;
 use h3o::geom::{ContainmentMode, Plotter, PlotterBuilder, Tiler, TilerBuilder};
-use h3o::{CellIndex, LatLng, Resolution};
+use h3o::{CellIndex, LatLng, Resolution, EARTH_RADIUS_KM};
 #[cfg(feature = "rayon")]
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 
+use crate::algorithm::radius::{arc_distance_rads, to_xyz};
 use crate::array::list::H3ListArray;
+use crate::array::to_geo::AntimeridianMode;
 use crate::array::{CellIndexArray, H3ListArrayBuilder};
 use crate::error::Error;
 
+/// Handling of polygons crossing the antimeridian or enclosing a pole.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AntimeridianHandling {
+    /// Polygons with an edge spanning more than 180° of longitude are assumed to cross
+    /// the antimeridian and are split there by h3o.
+    #[default]
+    Heuristic,
+
+    /// Unwrap the longitudes of the polygons, extend polygons enclosing a pole to the pole and
+    /// split them at the antimeridian before converting them to cells.
+    ///
+    /// This supports polygons using longitudes outside of [-180, 180], like 170° to 190°, as
+    /// well as polygons using wrapped longitudes, like 170° to -170°. Edges spanning more than
+    /// 180° of longitude are taken to cross the antimeridian.
+    Split,
+}
+
+/// Widening of the cells traced along lines. See [`ToCellsOptions::line_buffer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineBuffer {
+    /// All cells within `k` grid steps of the cells traced along the line.
+    K(u32),
+
+    /// All cells with their centroid within the given great-circle distance in meters of the
+    /// line, together with the cells traced along the line.
+    Meters(f64),
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct ToCellsOptions {
     pub(crate) h3_resolution: Resolution,
     pub(crate) containment_mode: ContainmentMode,
     pub(crate) compact: bool,
+    pub(crate) antimeridian_handling: AntimeridianHandling,
+    pub(crate) adaptive_min_resolution: Option<Resolution>,
+    pub(crate) max_cells: Option<usize>,
+    pub(crate) line_buffer: Option<LineBuffer>,
 }
 
 impl ToCellsOptions {
@@ -23,6 +62,10 @@ impl ToCellsOptions {
             h3_resolution,
             containment_mode: ContainmentMode::ContainsCentroid,
             compact: false,
+            antimeridian_handling: AntimeridianHandling::default(),
+            adaptive_min_resolution: None,
+            max_cells: None,
+            line_buffer: None,
         }
     }
 
@@ -36,10 +79,77 @@ impl ToCellsOptions {
         self
     }
 
+    pub fn antimeridian_handling(mut self, antimeridian_handling: AntimeridianHandling) -> Self {
+        self.antimeridian_handling = antimeridian_handling;
+        self
+    }
+
+    /// Fill polygons adaptively with cells between `min_resolution` and the resolution of
+    /// these options.
+    ///
+    /// Instead of generating all cells at the fine resolution and compacting them afterwards,
+    /// the polygons are refined top-down starting with the cells at `min_resolution`: cells
+    /// located completely inside or outside of a polygon are kept or dropped as a whole, only
+    /// cells near the boundary of the polygon are split into their children. This way only
+    /// the cells along the boundary are generated at the fine resolution.
+    ///
+    /// The result equals the compacted cells of the non-adaptive conversion, except that no
+    /// cells coarser than `min_resolution` are generated. The `compact` setting is ignored.
+    /// Points and lines are always converted using the fine resolution.
+    pub fn adaptive(mut self, min_resolution: Option<Resolution>) -> Self {
+        self.adaptive_min_resolution = min_resolution;
+        self
+    }
+
+    /// Build a covering of at most `max_cells` cells with mixed resolutions, similar to the
+    /// `RegionCoverer` of S2. This is useful to build cheap prefilters and query ranges.
+    ///
+    /// The covering starts with the cells intersecting the geometry at the minimum resolution
+    /// set using [`Self::adaptive`] or resolution 0 when that is not set. The cells with the
+    /// largest area outside of the geometry are then greedily replaced by the finer cells
+    /// covering their intersection with the geometry as long as this reduces the excess area and
+    /// the number of cells stays within `max_cells`. No cells finer than the resolution of these
+    /// options are used.
+    ///
+    /// When the covering at the minimum resolution already consists of more than `max_cells`
+    /// cells, the covering starts at the finest coarser resolution which fits. An error is
+    /// returned when even the covering at resolution 0 exceeds `max_cells`. The containment mode
+    /// and the `compact` setting are ignored. The cells of the covering may overlap, as the
+    /// children of H3 cells do not exactly cover their parent.
+    pub fn max_cells(mut self, max_cells: Option<usize>) -> Self {
+        self.max_cells = max_cells;
+        self
+    }
+
+    /// Widen the cells of lines and multilines to corridors of cells.
+    ///
+    /// The lines are traced through the cells they pass and the traced cells are expanded
+    /// by the given [`LineBuffer`]. Using meters, the distance of each candidate cell is checked
+    /// against the great-circle segments of the line, so the width of the corridor does not
+    /// depend on the latitude or the distortion of the grid. This is faster and more
+    /// accurate than converting a buffered polygon. Points and polygons are not affected.
+    pub fn line_buffer(mut self, line_buffer: Option<LineBuffer>) -> Self {
+        self.line_buffer = line_buffer;
+        self
+    }
+
     pub(crate) fn tiler(&self) -> Tiler {
-        TilerBuilder::new(self.h3_resolution)
-            .containment_mode(self.containment_mode)
-            .build()
+        let builder = TilerBuilder::new(self.h3_resolution).containment_mode(self.containment_mode);
+        match self.antimeridian_handling {
+            AntimeridianHandling::Heuristic => builder.build(),
+            AntimeridianHandling::Split => builder.disable_transmeridian_heuristic().build(),
+        }
+    }
+
+    /// Add a polygon using degrees to a tiler created by [`Self::tiler`].
+    pub(crate) fn add_polygon(&self, tiler: &mut Tiler, polygon: Polygon) -> Result<(), Error> {
+        match self.antimeridian_handling {
+            AntimeridianHandling::Heuristic => tiler.add(polygon)?,
+            AntimeridianHandling::Split => {
+                tiler.add_batch(AntimeridianMode::Split.apply_polygon(polygon))?
+            }
+        }
+        Ok(())
     }
 
     pub(crate) fn plotter(&self) -> Plotter {
@@ -310,6 +420,12 @@ pub fn geometry_to_cells(
     if geom.is_empty() {
         return Ok(vec![]);
     }
+    if let Some(max_cells) = options.max_cells {
+        return bounded_covering(geom, max_cells, options);
+    }
+    if let Some(min_resolution) = options.adaptive_min_resolution {
+        return adaptive_geometry_to_cells(geom, min_resolution, options);
+    }
 
     let mut cells = vec![];
     geometry_to_cells_internal(geom, options, &mut cells)?;
@@ -326,6 +442,639 @@ pub fn geometry_to_cells(
     Ok(cells)
 }
 
+/// The cells covering the polygonal parts of `geom` together with the fraction of the area
+/// of each cell located inside the geometry.
+///
+/// The cells are selected like with `ContainmentMode::Covers`, the containment mode and the
+/// compaction of `options` are ignored. Cells entirely within the geometry have a fraction
+/// of 1.0, all other cells are clipped with the geometry and the fraction is calculated from
+/// the geodesic areas. Cells not overlapping the geometry are omitted.
+pub fn geometry_to_cell_fractions(
+    geom: &Geometry,
+    options: &ToCellsOptions,
+) -> Result<Vec<(CellIndex, f64)>, Error> {
+    let mut polygons = vec![];
+    partition_polygons(geom, &mut polygons, &mut vec![]);
+    let multipolygon = MultiPolygon::new(polygons);
+    if multipolygon.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let geometry = Geometry::MultiPolygon(multipolygon.clone());
+    let options = options.compact(false).adaptive(None).max_cells(None);
+    let interior = geometry_to_cells(
+        &geometry,
+        &options.containment_mode(ContainmentMode::ContainsBoundary),
+    )?;
+    let covering = geometry_to_cells(
+        &geometry,
+        &options.containment_mode(ContainmentMode::Covers),
+    )?;
+
+    // the cell polygons are split at the antimeridian, so the geometry has to be as well
+    let clip = match options.antimeridian_handling {
+        AntimeridianHandling::Heuristic => multipolygon,
+        AntimeridianHandling::Split => AntimeridianMode::Split.apply_multipolygon(multipolygon),
+    };
+
+    let mut fractions = Vec::with_capacity(covering.len());
+    for cell in covering {
+        // both cell vecs are sorted
+        if interior.binary_search(&cell).is_ok() {
+            fractions.push((cell, 1.0));
+            continue;
+        }
+        let fraction = cell_fraction(cell, &clip);
+        if fraction > 0.0 {
+            fractions.push((cell, fraction));
+        }
+    }
+    Ok(fractions)
+}
+
+/// The fraction of the area of `cell` located inside `clip`.
+fn cell_fraction(cell: CellIndex, clip: &MultiPolygon) -> f64 {
+    let cell_multipolygon = cell_multipolygon(cell);
+
+    // the geodesic area depends on the winding order, which is not preserved by the clipping
+    let intersection = cell_multipolygon
+        .intersection(clip)
+        .orient(Direction::Default);
+    (intersection.geodesic_area_unsigned()
+        / cell_multipolygon
+            .orient(Direction::Default)
+            .geodesic_area_unsigned())
+    .min(1.0)
+}
+
+/// The polygon of `cell` split at the antimeridian.
+fn cell_multipolygon(cell: CellIndex) -> MultiPolygon {
+    AntimeridianMode::Split.apply_polygon(Polygon::new(LineString::from(cell.boundary()), vec![]))
+}
+
+/// Collect the polygonal parts of `geom` into `polygons` and all other parts into `others`.
+fn partition_polygons(geom: &Geometry, polygons: &mut Vec<Polygon>, others: &mut Vec<Geometry>) {
+    match geom {
+        Geometry::Polygon(polygon) => polygons.push(polygon.clone()),
+        Geometry::MultiPolygon(multi_polygon) => polygons.extend(multi_polygon.iter().cloned()),
+        Geometry::Rect(rect) => polygons.push(rect.to_polygon()),
+        Geometry::Triangle(triangle) => polygons.push(triangle.to_polygon()),
+        Geometry::GeometryCollection(geometry_collection) => geometry_collection
+            .iter()
+            .for_each(|g| partition_polygons(g, polygons, others)),
+        other => others.push(other.clone()),
+    }
+}
+
+fn validate_min_resolution(
+    min_resolution: Resolution,
+    options: &ToCellsOptions,
+) -> Result<(), Error> {
+    if min_resolution > options.h3_resolution {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "the minimum resolution {min_resolution} is finer than the resolution {}",
+            options.h3_resolution
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+fn adaptive_geometry_to_cells(
+    geom: &Geometry,
+    min_resolution: Resolution,
+    options: &ToCellsOptions,
+) -> Result<Vec<CellIndex>, Error> {
+    validate_min_resolution(min_resolution, options)?;
+
+    let mut polygons = vec![];
+    let mut others = vec![];
+    partition_polygons(geom, &mut polygons, &mut others);
+
+    let mut cells = vec![];
+    for other in others.iter() {
+        geometry_to_cells_internal(other, options, &mut cells)?;
+    }
+    if !polygons.is_empty() {
+        AdaptiveFill::new(polygons, options).fill(min_resolution, &mut cells)?;
+    }
+    Ok(compact_mixed(cells, min_resolution))
+}
+
+/// A cell of a covering with the area of the cell outside of the geometry in m².
+struct CoveringCell {
+    cell: CellIndex,
+    excess_area: f64,
+}
+
+impl PartialEq for CoveringCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CoveringCell {}
+
+impl PartialOrd for CoveringCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CoveringCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.excess_area
+            .total_cmp(&other.excess_area)
+            .then_with(|| self.cell.cmp(&other.cell))
+    }
+}
+
+fn bounded_covering(
+    geom: &Geometry,
+    max_cells: usize,
+    options: &ToCellsOptions,
+) -> Result<Vec<CellIndex>, Error> {
+    let min_resolution = options.adaptive_min_resolution.unwrap_or(Resolution::Zero);
+    validate_min_resolution(min_resolution, options)?;
+
+    let mut polygons = vec![];
+    let mut others = vec![];
+    partition_polygons(geom, &mut polygons, &mut others);
+    let clip = AntimeridianMode::Split.apply_multipolygon(MultiPolygon::new(polygons));
+    let mut parts = others;
+    parts.push(Geometry::MultiPolygon(clip.clone()));
+    let prepared = PreparedGeometry::from(GeometryCollection::new_from(parts));
+
+    let covering_cell = |cell: CellIndex| CoveringCell {
+        cell,
+        excess_area: cell.area_m2() * (1.0 - cell_fraction(cell, &clip)),
+    };
+
+    // start with the finest resolution not exceeding max_cells
+    let mut initial_resolution = min_resolution;
+    let mut covering = loop {
+        let initial_options = ToCellsOptions {
+            h3_resolution: initial_resolution,
+            containment_mode: ContainmentMode::Covers,
+            compact: false,
+            adaptive_min_resolution: None,
+            max_cells: None,
+            ..*options
+        };
+        let covering = geometry_to_cells(geom, &initial_options)?;
+        if covering.len() <= max_cells {
+            break covering.into_iter().collect::<HashSet<_>>();
+        }
+        initial_resolution = initial_resolution.pred().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "the geometry can not be covered by at most {max_cells} cells"
+            ))
+        })?;
+    };
+    let mut candidates = covering
+        .iter()
+        .filter(|cell| cell.resolution() < options.h3_resolution)
+        .map(|cell| covering_cell(*cell))
+        .collect::<BinaryHeap<_>>();
+
+    while let Some(candidate) = candidates.pop() {
+        let Some(child_resolution) = candidate.cell.resolution().succ() else {
+            continue;
+        };
+
+        // the children of a cell do not exactly cover the cell, so the children of the
+        // neighbours intersecting the cell are required as well.
+        let hexagon = cell_multipolygon(candidate.cell);
+        let replacement = candidate
+            .cell
+            .grid_disk::<Vec<_>>(1)
+            .into_iter()
+            .flat_map(|neighbour| neighbour.children(child_resolution))
+            .filter(|child| !covering.contains(child))
+            .filter(|child| {
+                let child_hexagon = cell_multipolygon(*child);
+                child_hexagon.intersects(&hexagon)
+                    && prepared.relate(&child_hexagon).is_intersects()
+            })
+            .map(covering_cell)
+            .collect::<Vec<_>>();
+
+        if covering.len() - 1 + replacement.len() > max_cells
+            || replacement.iter().map(|c| c.excess_area).sum::<f64>() >= candidate.excess_area
+        {
+            continue;
+        }
+
+        covering.remove(&candidate.cell);
+        for replacement_cell in replacement {
+            covering.insert(replacement_cell.cell);
+            if replacement_cell.cell.resolution() < options.h3_resolution {
+                candidates.push(replacement_cell);
+            }
+        }
+    }
+
+    let mut cells = covering.into_iter().collect::<Vec<_>>();
+    cells.sort_unstable();
+    Ok(cells)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CellPosition {
+    Inside,
+    Outside,
+    Boundary,
+}
+
+/// Top-down refinement of cells for [`ToCellsOptions::adaptive`].
+struct AdaptiveFill<'a> {
+    polygons: Vec<Polygon>,
+    options: &'a ToCellsOptions,
+
+    /// the polygons in degrees, split at the antimeridian like the cell polygons
+    clip: PreparedGeometry<'static>,
+    positions: HashMap<CellIndex, CellPosition>,
+}
+
+impl<'a> AdaptiveFill<'a> {
+    fn new(polygons: Vec<Polygon>, options: &'a ToCellsOptions) -> Self {
+        let multipolygon = MultiPolygon::new(polygons.clone());
+        let clip = match options.antimeridian_handling {
+            AntimeridianHandling::Heuristic => multipolygon,
+            AntimeridianHandling::Split => AntimeridianMode::Split.apply_multipolygon(multipolygon),
+        };
+        Self {
+            polygons,
+            options,
+            clip: PreparedGeometry::from(clip),
+            positions: Default::default(),
+        }
+    }
+
+    fn fill(&mut self, min_resolution: Resolution, out: &mut Vec<CellIndex>) -> Result<(), Error> {
+        let seed_options = ToCellsOptions {
+            h3_resolution: min_resolution,
+            containment_mode: ContainmentMode::Covers,
+            ..*self.options
+        };
+        let mut tiler = seed_options.tiler();
+        for polygon in self.polygons.iter() {
+            seed_options.add_polygon(&mut tiler, polygon.clone())?;
+        }
+
+        // the descendants of a cell extend slightly beyond its boundary, so the neighbours
+        // of the covering cells may have descendants within the polygons as well.
+        let mut seeds = tiler
+            .into_coverage()
+            .flat_map(|cell| cell.grid_disk::<Vec<_>>(1))
+            .collect::<Vec<_>>();
+        seeds.sort_unstable();
+        seeds.dedup();
+
+        for seed in seeds {
+            self.refine(seed, out);
+        }
+        Ok(())
+    }
+
+    fn refine(&mut self, cell: CellIndex, out: &mut Vec<CellIndex>) {
+        // the descendants of a cell are located within the cell and its direct neighbours. When
+        // none of them touches the boundary of the polygons, all descendants are located on the
+        // same side of the boundary as the cell itself.
+        let near_boundary = cell
+            .grid_disk::<Vec<_>>(1)
+            .into_iter()
+            .any(|neighbour| self.position(neighbour) == CellPosition::Boundary);
+
+        if !near_boundary {
+            if self.position(cell) == CellPosition::Inside {
+                out.push(cell);
+            }
+        } else if let Some(child_resolution) = cell
+            .resolution()
+            .succ()
+            .filter(|r| *r <= self.options.h3_resolution)
+        {
+            for child in cell.children(child_resolution) {
+                self.refine(child, out);
+            }
+        } else if self.selects(cell) {
+            out.push(cell);
+        }
+    }
+
+    fn selects(&mut self, cell: CellIndex) -> bool {
+        match self.options.containment_mode {
+            ContainmentMode::ContainsCentroid => self
+                .clip
+                .relate(&Point::from(Coord::from(LatLng::from(cell))))
+                .is_intersects(),
+            ContainmentMode::ContainsBoundary => self.position(cell) == CellPosition::Inside,
+            _ => self.position(cell) != CellPosition::Outside,
+        }
+    }
+
+    fn position(&mut self, cell: CellIndex) -> CellPosition {
+        if let Some(position) = self.positions.get(&cell) {
+            return *position;
+        }
+        let matrix = self.clip.relate(&cell_multipolygon(cell));
+        let position = if !matrix.is_intersects() {
+            CellPosition::Outside
+        } else if matrix.is_covers() {
+            CellPosition::Inside
+        } else {
+            CellPosition::Boundary
+        };
+        self.positions.insert(cell, position);
+        position
+    }
+}
+
+/// Sort and deduplicate cells of mixed resolutions. Cells contained in coarser cells of the
+/// set are removed and complete sets of children are replaced by their parents, up to
+/// `min_resolution`.
+fn compact_mixed(cells: Vec<CellIndex>, min_resolution: Resolution) -> Vec<CellIndex> {
+    let cell_set = cells.iter().copied().collect::<HashSet<_>>();
+    let mut by_resolution = vec![HashSet::default(); 16];
+    for cell in cell_set.iter() {
+        let is_covered = Resolution::range(min_resolution, cell.resolution())
+            .rev()
+            .skip(1)
+            .any(|r| cell.parent(r).is_some_and(|p| cell_set.contains(&p)));
+        if !is_covered {
+            by_resolution[u8::from(cell.resolution()) as usize].insert(*cell);
+        }
+    }
+
+    for resolution in Resolution::range(min_resolution, Resolution::Fifteen).rev() {
+        let Some(parent_resolution) = resolution.pred().filter(|r| *r >= min_resolution) else {
+            break;
+        };
+        let mut children_count = HashMap::<CellIndex, u64>::default();
+        for cell in by_resolution[u8::from(resolution) as usize].iter() {
+            if let Some(parent) = cell.parent(parent_resolution) {
+                *children_count.entry(parent).or_default() += 1;
+            }
+        }
+        for (parent, count) in children_count {
+            if count == parent.children_count(resolution) {
+                for child in parent.children(resolution) {
+                    by_resolution[u8::from(resolution) as usize].remove(&child);
+                }
+                by_resolution[u8::from(parent_resolution) as usize].insert(parent);
+            }
+        }
+    }
+
+    let mut cells = by_resolution.into_iter().flatten().collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells
+}
+
+/// The cells covering the geometries of an array with the fraction of the area of each cell
+/// located inside the geometry. See [`geometry_to_cell_fractions`].
+pub struct CellFractions {
+    /// the position of the geometry in the input array
+    pub row_index: UInt64Array,
+    pub cells: CellIndexArray,
+    pub fractions: Float64Array,
+}
+
+pub trait ToCellFractions {
+    fn to_cell_fractions(&self, options: &ToCellsOptions) -> Result<CellFractions, Error>;
+}
+
+impl<T> ToCellFractions for &[T]
+where
+    T: ToClonedGeometry + Sync,
+{
+    fn to_cell_fractions(&self, options: &ToCellsOptions) -> Result<CellFractions, Error> {
+        positions_to_cell_fractions(
+            self.len(),
+            |pos| Ok(self[pos].to_cloned_geometry()),
+            options,
+        )
+    }
+}
+
+/// Build the [`CellFractions`] of the geometries at the positions `0..len`, in parallel when
+/// the `rayon` feature is enabled.
+pub(crate) fn positions_to_cell_fractions<F>(
+    len: usize,
+    get_geometry: F,
+    options: &ToCellsOptions,
+) -> Result<CellFractions, Error>
+where
+    F: Fn(usize) -> Result<Option<Geometry>, Error> + Sync,
+{
+    let geometry_fractions = |pos: usize| match get_geometry(pos)? {
+        Some(geom) => geometry_to_cell_fractions(&geom, options),
+        None => Ok(vec![]),
+    };
+
+    #[cfg(not(feature = "rayon"))]
+    let fraction_vecs = (0..len)
+        .map(geometry_fractions)
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    #[cfg(feature = "rayon")]
+    let fraction_vecs = (0..len)
+        .into_par_iter()
+        .map(geometry_fractions)
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let capacity = fraction_vecs.iter().map(Vec::len).sum();
+    let mut row_index = Vec::with_capacity(capacity);
+    let mut cells = Vec::with_capacity(capacity);
+    let mut fractions = Vec::with_capacity(capacity);
+    for (pos, fraction_vec) in fraction_vecs.into_iter().enumerate() {
+        for (cell, fraction) in fraction_vec {
+            row_index.push(pos as u64);
+            cells.push(cell);
+            fractions.push(fraction);
+        }
+    }
+    Ok(CellFractions {
+        row_index: UInt64Array::from(row_index),
+        cells: CellIndexArray::from(cells),
+        fractions: Float64Array::from(fractions),
+    })
+}
+
+/// The cells intersecting `geom` classified as interior or boundary cells. The boolean is
+/// `true` for cells located completely inside the polygonal parts of the geometry, which are
+/// the cells of `ContainmentMode::ContainsBoundary`. All other cells of
+/// `ContainmentMode::IntersectsBoundary` are boundary cells. Cells of points and lines are
+/// always boundary cells unless they are located in the interior of a polygon.
+///
+/// The geometry is converted to cells only once. Exact geometric tests are only done for the
+/// cells along the rings of the polygons. The containment mode and the compaction, adaptive
+/// and `max_cells` settings of `options` are ignored. The returned cells are sorted.
+pub fn geometry_to_classified_cells(
+    geom: &Geometry,
+    options: &ToCellsOptions,
+) -> Result<Vec<(CellIndex, bool)>, Error> {
+    if geom.is_empty() {
+        return Ok(vec![]);
+    }
+    let options = ToCellsOptions {
+        containment_mode: ContainmentMode::IntersectsBoundary,
+        compact: false,
+        adaptive_min_resolution: None,
+        max_cells: None,
+        ..*options
+    };
+
+    let mut polygons = vec![];
+    let mut others = vec![];
+    partition_polygons(geom, &mut polygons, &mut others);
+
+    let mut classified = vec![];
+    for other in others.iter() {
+        let mut cells = vec![];
+        geometry_to_cells_internal(other, &options, &mut cells)?;
+        classified.extend(cells.into_iter().map(|cell| (cell, false)));
+    }
+
+    if !polygons.is_empty() {
+        // the cells intersecting the rings are located within the direct neighbours of the
+        // cells along the densified rings.
+        let max_segment_length_m = options.h3_resolution.edge_length_m() / 2.0;
+        let mut plotter = options.plotter();
+        for polygon in polygons.iter() {
+            for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                plotter.add_batch(densify_lines(ring, max_segment_length_m)?)?;
+            }
+        }
+        let mut ring_cells = vec![];
+        push_plotter_contents(&mut ring_cells, plotter)?;
+        let near_rings = ring_cells
+            .into_iter()
+            .flat_map(|cell| cell.grid_disk::<Vec<_>>(1))
+            .collect::<HashSet<_>>();
+
+        let mut tiler = options.tiler();
+        for polygon in polygons.iter() {
+            options.add_polygon(&mut tiler, polygon.clone())?;
+        }
+
+        let clip = PreparedGeometry::from(
+            AntimeridianMode::Split.apply_multipolygon(MultiPolygon::new(polygons)),
+        );
+        classified.extend(tiler.into_coverage().map(|cell| {
+            let is_interior =
+                !near_rings.contains(&cell) || clip.relate(&cell_multipolygon(cell)).is_covers();
+            (cell, is_interior)
+        }));
+    }
+
+    // deduplicate, a cell is an interior cell if it is the interior of any part
+    classified.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    classified.dedup_by_key(|(cell, _)| *cell);
+    Ok(classified)
+}
+
+/// The lines of `line_string` using degrees split into segments with a great-circle distance
+/// between their endpoints of at most `max_length_m` meters.
+fn densify_lines(line_string: &LineString, max_length_m: f64) -> Result<Vec<Line>, Error> {
+    let mut lines = vec![];
+    for line in line_string.lines() {
+        let length_m = LatLng::try_from(line.start)?.distance_m(LatLng::try_from(line.end)?);
+        let num_segments = ((length_m / max_length_m).ceil() as usize).max(1);
+        let delta = line.delta() / num_segments as f64;
+        lines.extend((0..num_segments).map(|i| {
+            Line::new(
+                line.start + delta * i as f64,
+                if i + 1 == num_segments {
+                    line.end
+                } else {
+                    line.start + delta * (i + 1) as f64
+                },
+            )
+        }));
+    }
+    Ok(lines)
+}
+
+/// Cells of geometries classified as interior or boundary cells. See
+/// [`geometry_to_classified_cells`].
+pub struct ClassifiedCellLists<O: OffsetSizeTrait> {
+    pub cells: H3ListArray<CellIndex, O>,
+
+    /// for each value of the lists of `cells`: `true` for interior cells and `false` for
+    /// boundary cells
+    pub is_interior: BooleanArray,
+}
+
+pub trait ToClassifiedCellListArray<O: OffsetSizeTrait> {
+    fn to_classified_celllistarray(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<ClassifiedCellLists<O>, Error>;
+}
+
+impl<T, O: OffsetSizeTrait> ToClassifiedCellListArray<O> for &[T]
+where
+    T: ToClonedGeometry + Sync,
+{
+    fn to_classified_celllistarray(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<ClassifiedCellLists<O>, Error> {
+        positions_to_classified_cells(
+            self.len(),
+            |pos| Ok(self[pos].to_cloned_geometry()),
+            options,
+        )
+    }
+}
+
+/// Build the [`ClassifiedCellLists`] of the geometries at the positions `0..len`, in parallel
+/// when the `rayon` feature is enabled.
+pub(crate) fn positions_to_classified_cells<O, F>(
+    len: usize,
+    get_geometry: F,
+    options: &ToCellsOptions,
+) -> Result<ClassifiedCellLists<O>, Error>
+where
+    O: OffsetSizeTrait,
+    F: Fn(usize) -> Result<Option<Geometry>, Error> + Sync,
+{
+    let classify = |pos: usize| {
+        get_geometry(pos)?
+            .map(|geom| geometry_to_classified_cells(&geom, options))
+            .transpose()
+    };
+
+    #[cfg(not(feature = "rayon"))]
+    let classified_vecs = (0..len).map(classify).collect::<Result<Vec<_>, Error>>()?;
+
+    #[cfg(feature = "rayon")]
+    let classified_vecs = (0..len)
+        .into_par_iter()
+        .map(classify)
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let is_interior = classified_vecs
+        .iter()
+        .flatten()
+        .flat_map(|classified| classified.iter().map(|(_, is_interior)| *is_interior))
+        .collect::<Vec<_>>();
+    let cells = cell_vecs_to_h3listarray(
+        classified_vecs
+            .into_iter()
+            .map(|classified| {
+                classified.map(|classified| classified.into_iter().map(|(cell, _)| cell).collect())
+            })
+            .collect(),
+    )?;
+    Ok(ClassifiedCellLists {
+        cells,
+        is_interior: BooleanArray::from(is_interior),
+    })
+}
+
 fn geometry_to_cells_internal(
     geom: &Geometry,
     options: &ToCellsOptions,
@@ -335,19 +1084,13 @@ fn geometry_to_cells_internal(
         Geometry::Point(pt) => {
             out_cells.push(LatLng::try_from(pt.0)?.to_cell(options.h3_resolution))
         }
-        Geometry::Line(line) => {
-            let mut plotter = options.plotter();
-            plotter.add(*line)?;
-            push_plotter_contents(out_cells, plotter)?;
-        }
+        Geometry::Line(line) => push_line_cells(out_cells, [*line], options)?,
         Geometry::LineString(line_string) => {
-            let mut plotter = options.plotter();
-            plotter.add_batch(line_string.lines())?;
-            push_plotter_contents(out_cells, plotter)?;
+            push_line_cells(out_cells, line_string.lines(), options)?
         }
         Geometry::Polygon(polygon) => {
             let mut tiler = options.tiler();
-            tiler.add(polygon.clone())?;
+            options.add_polygon(&mut tiler, polygon.clone())?;
             out_cells.extend(tiler.into_coverage());
         }
         Geometry::MultiPoint(multi_point) => {
@@ -356,16 +1099,16 @@ fn geometry_to_cells_internal(
                 out_cells.push(LatLng::try_from(point.0)?.to_cell(options.h3_resolution))
             }
         }
-        Geometry::MultiLineString(multi_line_string) => {
-            let mut plotter = options.plotter();
-            for line_string in multi_line_string.iter() {
-                plotter.add_batch(line_string.lines())?;
-            }
-            push_plotter_contents(out_cells, plotter)?;
-        }
+        Geometry::MultiLineString(multi_line_string) => push_line_cells(
+            out_cells,
+            multi_line_string.iter().flat_map(|ls| ls.lines()),
+            options,
+        )?,
         Geometry::MultiPolygon(multi_polygon) => {
             let mut tiler = options.tiler();
-            tiler.add_batch(multi_polygon.iter().cloned())?;
+            for polygon in multi_polygon.iter() {
+                options.add_polygon(&mut tiler, polygon.clone())?;
+            }
             out_cells.extend(tiler.into_coverage());
         }
         Geometry::GeometryCollection(geometry_collection) => geometry_collection
@@ -373,18 +1116,95 @@ fn geometry_to_cells_internal(
             .try_for_each(|g| geometry_to_cells_internal(g, options, out_cells))?,
         Geometry::Rect(rect) => {
             let mut tiler = options.tiler();
-            tiler.add(rect.to_polygon())?;
+            options.add_polygon(&mut tiler, rect.to_polygon())?;
             out_cells.extend(tiler.into_coverage());
         }
         Geometry::Triangle(triangle) => {
             let mut tiler = options.tiler();
-            tiler.add(triangle.to_polygon())?;
+            options.add_polygon(&mut tiler, triangle.to_polygon())?;
             out_cells.extend(tiler.into_coverage());
         }
     }
     Ok(())
 }
 
+/// Trace the lines through the cells and apply the line buffer of the options.
+fn push_line_cells<I>(
+    out_cells: &mut Vec<CellIndex>,
+    lines: I,
+    options: &ToCellsOptions,
+) -> Result<(), Error>
+where
+    I: IntoIterator<Item = Line>,
+{
+    match options.line_buffer {
+        None => {
+            let mut plotter = options.plotter();
+            plotter.add_batch(lines)?;
+            push_plotter_contents(out_cells, plotter)
+        }
+        Some(LineBuffer::K(k)) => {
+            let mut plotter = options.plotter();
+            plotter.add_batch(lines)?;
+            let mut traced = vec![];
+            push_plotter_contents(&mut traced, plotter)?;
+            traced.sort_unstable();
+            traced.dedup();
+            for cell in traced {
+                out_cells.extend(cell.grid_disk::<Vec<_>>(k));
+            }
+            Ok(())
+        }
+        Some(LineBuffer::Meters(distance_m)) => {
+            if !(distance_m.is_finite() && distance_m >= 0.0) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "the line buffer distance must be a non-negative number of meters, got {distance_m}"
+                ))
+                .into());
+            }
+            let distance_rads = distance_m / (EARTH_RADIUS_KM * 1000.0);
+            lines
+                .into_iter()
+                .try_for_each(|line| push_buffered_line(out_cells, line, distance_rads, options))
+        }
+    }
+}
+
+/// The cells traced along `line` and all cells with their centroid within `distance_rads`
+/// of the great-circle segment.
+///
+/// The cells are collected by growing the traced cells to their neighbours as long as
+/// the centroids of the neighbours are within the distance. The centroids within the
+/// distance form a connected area around the traced cells.
+fn push_buffered_line(
+    out_cells: &mut Vec<CellIndex>,
+    line: Line,
+    distance_rads: f64,
+    options: &ToCellsOptions,
+) -> Result<(), Error> {
+    let start = to_xyz(LatLng::try_from(line.start)?);
+    let end = to_xyz(LatLng::try_from(line.end)?);
+
+    let mut plotter = options.plotter();
+    plotter.add(line)?;
+    let mut queue = vec![];
+    push_plotter_contents(&mut queue, plotter)?;
+
+    let mut visited = queue.iter().copied().collect::<HashSet<_>>();
+    while let Some(cell) = queue.pop() {
+        out_cells.push(cell);
+        for neighbour in cell.grid_disk::<Vec<_>>(1) {
+            if visited.insert(neighbour)
+                && arc_distance_rads(&to_xyz(LatLng::from(neighbour)), &start, &end)
+                    <= distance_rads
+            {
+                queue.push(neighbour);
+            }
+        }
+    }
+    Ok(())
+}
+
 fn push_plotter_contents(out_cells: &mut Vec<CellIndex>, plotter: Plotter) -> Result<(), Error> {
     let cell_iter = plotter.plot();
     out_cells.reserve(cell_iter.size_hint().0);
@@ -405,9 +1225,55 @@ fn to_cells(
 
 #[cfg(test)]
 mod tests {
-    use crate::array::from_geo::{ToCellIndexArray, ToCellsOptions};
-    use geo_types::Rect;
-    use h3o::Resolution;
+    use crate::array::from_geo::{
+        geometry_to_cells, geometry_to_classified_cells, AntimeridianHandling, LineBuffer,
+        ToCellFractions, ToCellIndexArray, ToCellsOptions,
+    };
+    use geo::{GeodesicArea, MapCoords};
+    use geo_types::{Coord, Geometry, LineString, MultiPolygon, Polygon, Rect};
+    use h3o::geom::ContainmentMode;
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn cell_fractions() {
+        let rect = Rect::new((10.0, 10.0), (10.5, 10.5));
+        let geometries = vec![Some(Geometry::from(rect)), None, Some(Geometry::from(rect))];
+        let options = ToCellsOptions::from(Resolution::Six);
+        let fractions = geometries.as_slice().to_cell_fractions(&options).unwrap();
+
+        let covering = geometry_to_cells(
+            &Geometry::from(rect),
+            &options.containment_mode(ContainmentMode::Covers),
+        )
+        .unwrap();
+        assert_eq!(fractions.cells.len(), 2 * covering.len());
+        assert_eq!(fractions.row_index.value(0), 0);
+        assert_eq!(fractions.row_index.value(fractions.row_index.len() - 1), 2);
+
+        let fraction_values = fractions.fractions.values();
+        assert!(fraction_values.iter().all(|f| *f > 0.0 && *f <= 1.0));
+        assert!(fraction_values.iter().any(|f| *f < 1.0));
+        assert!(fraction_values.contains(&1.0));
+
+        // the weighted cell areas sum up to the area of the rect
+        let covered_area: f64 = fractions
+            .cells
+            .iter()
+            .zip(fraction_values.iter())
+            .take(covering.len())
+            .map(|(cell, f)| cell.unwrap().area_m2() * f)
+            .sum();
+        let rect_area = rect.to_polygon().geodesic_area_unsigned();
+        assert!((covered_area / rect_area - 1.0).abs() < 0.01);
+
+        // the fractions always use cells of the resolution
+        let bounded = geometries
+            .as_slice()
+            .to_cell_fractions(&options.max_cells(Some(5)))
+            .unwrap();
+        assert!(bounded.cells.iter().eq(fractions.cells.iter()));
+        assert_eq!(bounded.fractions, fractions.fractions);
+    }
 
     #[test]
     fn from_rect() {
@@ -422,4 +1288,308 @@ mod tests {
             assert_eq!(r, Some(Resolution::Four));
         }
     }
+
+    /// Read a multipolygon fixture from the `testdata` directory. The fixtures are taken from
+    /// the Natural Earth 1:110m countries.
+    fn load_multipolygon(name: &str) -> MultiPolygon {
+        let path = format!("{}/testdata/{name}", env!("CARGO_MANIFEST_DIR"));
+        let wkt = std::fs::read_to_string(path).unwrap();
+        let body = wkt
+            .trim()
+            .strip_prefix("MULTIPOLYGON (((")
+            .and_then(|body| body.strip_suffix(")))"))
+            .unwrap();
+        let parse_ring = |ring: &str| {
+            LineString::from(
+                ring.split(", ")
+                    .map(|coord| {
+                        let (x, y) = coord.split_once(' ').unwrap();
+                        (x.parse::<f64>().unwrap(), y.parse::<f64>().unwrap())
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        };
+        MultiPolygon::new(
+            body.split(")), ((")
+                .map(|polygon| {
+                    let mut rings = polygon.split("), (").map(parse_ring);
+                    Polygon::new(rings.next().unwrap(), rings.collect())
+                })
+                .collect(),
+        )
+    }
+
+    fn split_cells(geom: impl Into<Geometry>, resolution: Resolution) -> Vec<h3o::CellIndex> {
+        let options =
+            ToCellsOptions::new(resolution).antimeridian_handling(AntimeridianHandling::Split);
+        geometry_to_cells(&geom.into(), &options).unwrap()
+    }
+
+    #[test]
+    fn antimeridian_split_fiji() {
+        // Natural Earth splits Fiji at the antimeridian
+        let fiji = load_multipolygon("fiji.wkt");
+        let cells = split_cells(fiji.clone(), Resolution::Six);
+        assert!(cells.len() > 100);
+        assert!(cells.iter().any(|cell| LatLng::from(*cell).lng() > 179.0));
+        assert!(cells.iter().any(|cell| LatLng::from(*cell).lng() < -179.0));
+
+        // longitudes exceeding 180°
+        let unwrapped = fiji.map_coords(|c| Coord {
+            x: if c.x < 0.0 { c.x + 360.0 } else { c.x },
+            y: c.y,
+        });
+        assert_eq!(cells, split_cells(unwrapped.clone(), Resolution::Six));
+
+        // longitudes below -180°
+        let shifted = unwrapped.map_coords(|c| Coord {
+            x: c.x - 360.0,
+            y: c.y,
+        });
+        assert_eq!(cells, split_cells(shifted, Resolution::Six));
+    }
+
+    #[test]
+    fn antimeridian_split_antarctica() {
+        // the mainland is closed via the south pole, as done by Natural Earth
+        let antarctica = load_multipolygon("antarctica.wkt");
+        let cells = split_cells(antarctica.clone(), Resolution::Three);
+
+        // only the coast of the mainland, wrapping at the antimeridian
+        let mut coast = antarctica.clone();
+        let mainland = coast
+            .0
+            .iter_mut()
+            .max_by_key(|polygon| polygon.exterior().0.len())
+            .unwrap();
+        mainland.exterior_mut(|ring| {
+            ring.0.retain(|c| c.y > -90.0);
+            ring.0
+                .dedup_by(|a, b| a.y == b.y && (a.x.abs() - 180.0).abs() < 1e-6);
+        });
+        assert_eq!(cells, split_cells(coast, Resolution::Three));
+
+        let contains = |lat: f64, lng: f64| {
+            let cell = LatLng::new(lat, lng).unwrap().to_cell(Resolution::Three);
+            cells.binary_search(&cell).is_ok()
+        };
+        assert!(contains(-90.0, 0.0));
+        for lng in [-179.0, -90.0, 0.0, 90.0, 179.0] {
+            assert!(contains(-85.0, lng));
+            assert!(!contains(-55.0, lng));
+        }
+    }
+
+    #[test]
+    fn adaptive_polyfill() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (10.0, 10.0),
+                (12.0, 10.2),
+                (11.5, 11.0),
+                (12.2, 12.0),
+                (10.3, 11.8),
+                (10.0, 10.0),
+            ]),
+            vec![],
+        );
+        let geometry = Geometry::from(polygon);
+
+        for containment_mode in [
+            ContainmentMode::ContainsCentroid,
+            ContainmentMode::ContainsBoundary,
+            ContainmentMode::Covers,
+        ] {
+            let options = ToCellsOptions::new(Resolution::Six).containment_mode(containment_mode);
+            let compacted = geometry_to_cells(&geometry, &options.compact(true)).unwrap();
+            let adaptive =
+                geometry_to_cells(&geometry, &options.adaptive(Some(Resolution::Two))).unwrap();
+            assert_eq!(adaptive, compacted);
+            assert!(adaptive
+                .iter()
+                .any(|cell| cell.resolution() < Resolution::Six));
+        }
+
+        // cells are not coarser than the minimum resolution
+        let options = ToCellsOptions::new(Resolution::Six).adaptive(Some(Resolution::Five));
+        let cells = geometry_to_cells(&geometry, &options).unwrap();
+        assert!(cells
+            .iter()
+            .all(|cell| cell.resolution() >= Resolution::Five));
+        assert!(cells
+            .iter()
+            .any(|cell| cell.resolution() == Resolution::Five));
+
+        let options = ToCellsOptions::new(Resolution::Six).adaptive(Some(Resolution::Seven));
+        assert!(geometry_to_cells(&geometry, &options).is_err());
+
+        // polygons along the antimeridian
+        let fiji = Geometry::from(load_multipolygon("fiji.wkt"));
+        for antimeridian_handling in [AntimeridianHandling::Heuristic, AntimeridianHandling::Split]
+        {
+            let options =
+                ToCellsOptions::new(Resolution::Seven).antimeridian_handling(antimeridian_handling);
+            let compacted = geometry_to_cells(&fiji, &options.compact(true)).unwrap();
+            let adaptive =
+                geometry_to_cells(&fiji, &options.adaptive(Some(Resolution::Zero))).unwrap();
+            assert_eq!(adaptive, compacted);
+        }
+    }
+
+    #[test]
+    fn bounded_covering() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (10.0, 10.0),
+                (12.0, 10.2),
+                (11.5, 11.0),
+                (12.2, 12.0),
+                (10.3, 11.8),
+                (10.0, 10.0),
+            ]),
+            vec![],
+        );
+        let geometry = Geometry::from(polygon);
+        let inner_cells =
+            geometry_to_cells(&geometry, &ToCellsOptions::new(Resolution::Seven)).unwrap();
+
+        let mut previous_area = f64::INFINITY;
+        for max_cells in [10, 30, 100] {
+            let options = ToCellsOptions::new(Resolution::Seven)
+                .adaptive(Some(Resolution::Two))
+                .max_cells(Some(max_cells));
+            let covering = geometry_to_cells(&geometry, &options).unwrap();
+            assert!(!covering.is_empty());
+            assert!(covering.len() <= max_cells);
+
+            // all points of the polygon are located within a cell of the covering
+            for cell in inner_cells.iter() {
+                let ll = LatLng::from(*cell);
+                assert!(covering.iter().any(|c| ll.to_cell(c.resolution()) == *c));
+            }
+
+            let area: f64 = covering.iter().map(|c| c.area_m2()).sum();
+            assert!(area < previous_area);
+            previous_area = area;
+        }
+
+        // the covering at the minimum resolution exceeds max_cells
+        let options = ToCellsOptions::new(Resolution::Seven)
+            .adaptive(Some(Resolution::Six))
+            .max_cells(Some(10));
+        let covering = geometry_to_cells(&geometry, &options).unwrap();
+        assert!(!covering.is_empty());
+        assert!(covering.len() <= 10);
+
+        let options = ToCellsOptions::new(Resolution::Seven).max_cells(Some(0));
+        assert!(geometry_to_cells(&geometry, &options).is_err());
+    }
+
+    #[test]
+    fn classified_cells() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(10.0, 10.0), (11.0, 10.0), (11.0, 11.0), (10.0, 10.0)]),
+            vec![LineString::from(vec![
+                (10.7, 10.2),
+                (10.8, 10.2),
+                (10.8, 10.3),
+                (10.7, 10.2),
+            ])],
+        );
+        let geometry = Geometry::from(polygon);
+        let options = ToCellsOptions::new(Resolution::Seven);
+        let classified = geometry_to_classified_cells(&geometry, &options).unwrap();
+
+        let intersecting = geometry_to_cells(
+            &geometry,
+            &options.containment_mode(ContainmentMode::IntersectsBoundary),
+        )
+        .unwrap();
+        let interior = geometry_to_cells(
+            &geometry,
+            &options.containment_mode(ContainmentMode::ContainsBoundary),
+        )
+        .unwrap();
+        assert_eq!(
+            classified.iter().map(|(cell, _)| *cell).collect::<Vec<_>>(),
+            intersecting
+        );
+        assert_eq!(
+            classified
+                .iter()
+                .filter_map(|(cell, is_interior)| is_interior.then_some(*cell))
+                .collect::<Vec<_>>(),
+            interior
+        );
+    }
+
+    #[test]
+    fn line_buffer() {
+        let line = Geometry::from(LineString::from(vec![
+            (10.0, 50.0),
+            (10.3, 50.1),
+            (10.5, 50.4),
+        ]));
+        let options = ToCellsOptions::new(Resolution::Eight);
+        let traced = geometry_to_cells(&line, &options).unwrap();
+
+        let k_buffered =
+            geometry_to_cells(&line, &options.line_buffer(Some(LineBuffer::K(2)))).unwrap();
+        assert!(traced
+            .iter()
+            .all(|cell| k_buffered.binary_search(cell).is_ok()));
+        assert!(k_buffered.len() > traced.len() * 3);
+
+        let distance_m = 2000.0;
+        let buffered = geometry_to_cells(
+            &line,
+            &options.line_buffer(Some(LineBuffer::Meters(distance_m))),
+        )
+        .unwrap();
+        assert!(traced
+            .iter()
+            .all(|cell| buffered.binary_search(cell).is_ok()));
+
+        // compare with the distances to the densely sampled line. The samples are interpolated
+        // linearly in degrees and deviate slightly from the great-circle segments.
+        let Geometry::LineString(line_string) = &line else {
+            unreachable!()
+        };
+        let samples = line_string
+            .lines()
+            .flat_map(|l| {
+                (0..=1000).map(move |i| {
+                    let f = i as f64 / 1000.0;
+                    LatLng::new(
+                        l.start.y + (l.end.y - l.start.y) * f,
+                        l.start.x + (l.end.x - l.start.x) * f,
+                    )
+                    .unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+        let distance_to_line = |cell: h3o::CellIndex| {
+            let centroid = LatLng::from(cell);
+            samples
+                .iter()
+                .map(|ll| ll.distance_m(centroid))
+                .fold(f64::INFINITY, f64::min)
+        };
+        for cell in buffered.iter() {
+            assert!(
+                traced.binary_search(cell).is_ok() || distance_to_line(*cell) <= distance_m + 50.0
+            );
+        }
+        let candidates =
+            geometry_to_cells(&line, &options.line_buffer(Some(LineBuffer::K(10)))).unwrap();
+        for cell in candidates {
+            if distance_to_line(cell) < distance_m - 50.0 {
+                assert!(buffered.binary_search(&cell).is_ok());
+            }
+        }
+
+        assert!(
+            geometry_to_cells(&line, &options.line_buffer(Some(LineBuffer::Meters(-1.0)))).is_err()
+        );
+    }
 }
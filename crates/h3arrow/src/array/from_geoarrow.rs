@@ -1,16 +1,19 @@
 use super::from_geo::{
-    cell_vecs_to_h3listarray, IterToCellIndexArray, IterToCellListArray, ToCellIndexArray,
-    ToCellListArray, ToCellsOptions,
+    cell_vecs_to_h3listarray, positions_to_cell_fractions, positions_to_classified_cells,
+    CellFractions, ClassifiedCellLists, IterToCellIndexArray, IterToCellListArray, ToCellFractions,
+    ToCellIndexArray, ToCellListArray, ToCellsOptions, ToClassifiedCellListArray,
 };
 use crate::algorithm::CompactOp;
 use crate::array::from_geo::geometry_to_cells;
 use crate::array::{CellIndexArray, H3ListArray};
 use crate::error::Error;
-use arrow::array::OffsetSizeTrait;
+use arrow::array::{Array, GenericStringArray, OffsetSizeTrait};
 use geo_types::Geometry;
 use geoarrow::array::WKBArray;
 use geoarrow::trait_::ArrayAccessor;
 use geoarrow::ArrayBase;
+use geozero::wkt::Wkt;
+use geozero::ToGeo;
 use h3o::CellIndex;
 #[cfg(feature = "rayon")]
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
@@ -35,6 +38,29 @@ macro_rules! impl_to_cells {
                     .to_cellindexarray(options)
             }
         }
+
+        impl ToCellFractions for $array_type {
+            fn to_cell_fractions(&self, options: &ToCellsOptions) -> Result<CellFractions, Error> {
+                positions_to_cell_fractions(
+                    self.len(),
+                    |pos| Ok(self.get_as_geo(pos).map(Geometry::from)),
+                    options,
+                )
+            }
+        }
+
+        impl<O: OffsetSizeTrait> ToClassifiedCellListArray<O> for $array_type {
+            fn to_classified_celllistarray(
+                &self,
+                options: &ToCellsOptions,
+            ) -> Result<ClassifiedCellLists<O>, Error> {
+                positions_to_classified_cells(
+                    self.len(),
+                    |pos| Ok(self.get_as_geo(pos).map(Geometry::from)),
+                    options,
+                )
+            }
+        }
     };
 }
 
@@ -50,32 +76,135 @@ impl<O: OffsetSizeTrait> ToCellListArray<O> for WKBArray<O> {
         &self,
         options: &ToCellsOptions,
     ) -> Result<H3ListArray<CellIndex, O>, Error> {
-        #[cfg(not(feature = "rayon"))]
-        let pos_iter = 0..self.len();
+        positions_to_celllistarray(self.len(), |pos| Ok(self.get_as_geo(pos)), options)
+    }
+}
 
-        #[cfg(feature = "rayon")]
-        let pos_iter = (0..self.len()).into_par_iter();
+impl<O: OffsetSizeTrait> ToCellIndexArray for WKBArray<O> {
+    fn to_cellindexarray(&self, options: &ToCellsOptions) -> Result<CellIndexArray, Error> {
+        celllistarray_to_cellindexarray(self.to_celllistarray(options)?, options)
+    }
+}
 
-        let cell_vecs = pos_iter
-            .map(|pos| {
-                self.get_as_geo(pos)
-                    .map(|geom| geometry_to_cells(&geom, options))
-                    .transpose()
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+impl<O: OffsetSizeTrait> ToCellFractions for WKBArray<O> {
+    fn to_cell_fractions(&self, options: &ToCellsOptions) -> Result<CellFractions, Error> {
+        positions_to_cell_fractions(self.len(), |pos| Ok(self.get_as_geo(pos)), options)
+    }
+}
 
-        cell_vecs_to_h3listarray(cell_vecs)
+impl<O: OffsetSizeTrait> ToClassifiedCellListArray<O> for WKBArray<O> {
+    fn to_classified_celllistarray(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<ClassifiedCellLists<O>, Error> {
+        positions_to_classified_cells(self.len(), |pos| Ok(self.get_as_geo(pos)), options)
     }
 }
 
-impl<O: OffsetSizeTrait> ToCellIndexArray for WKBArray<O> {
-    fn to_cellindexarray(&self, options: &ToCellsOptions) -> Result<CellIndexArray, Error> {
-        let cellindexarray = self.to_celllistarray(options)?.into_flattened()?;
+/// Geometries encoded as WKT in an arrow string array.
+pub struct WKTArray<O: OffsetSizeTrait>(GenericStringArray<O>);
+
+impl<O: OffsetSizeTrait> WKTArray<O> {
+    pub fn new(array: GenericStringArray<O>) -> Self {
+        Self(array)
+    }
 
-        if options.compact {
-            cellindexarray.compact()
-        } else {
-            Ok(cellindexarray) // may contain duplicates
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parse the WKT at `pos`. Returns `None` for null values.
+    pub fn get_as_geo(&self, pos: usize) -> Result<Option<Geometry>, Error> {
+        if self.0.is_null(pos) {
+            return Ok(None);
         }
+        Wkt(self.0.value(pos))
+            .to_geo()
+            .map(Some)
+            .map_err(|_| Error::InvalidWKT)
+    }
+}
+
+impl<O: OffsetSizeTrait> From<GenericStringArray<O>> for WKTArray<O> {
+    fn from(array: GenericStringArray<O>) -> Self {
+        Self::new(array)
+    }
+}
+
+impl<O: OffsetSizeTrait> ToCellListArray<O> for WKTArray<O> {
+    fn to_celllistarray(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<H3ListArray<CellIndex, O>, Error> {
+        positions_to_celllistarray(self.len(), |pos| self.get_as_geo(pos), options)
+    }
+}
+
+impl<O: OffsetSizeTrait> ToCellIndexArray for WKTArray<O> {
+    fn to_cellindexarray(&self, options: &ToCellsOptions) -> Result<CellIndexArray, Error> {
+        celllistarray_to_cellindexarray(
+            ToCellListArray::<O>::to_celllistarray(self, options)?,
+            options,
+        )
+    }
+}
+
+impl<O: OffsetSizeTrait> ToCellFractions for WKTArray<O> {
+    fn to_cell_fractions(&self, options: &ToCellsOptions) -> Result<CellFractions, Error> {
+        positions_to_cell_fractions(self.len(), |pos| self.get_as_geo(pos), options)
+    }
+}
+
+impl<O: OffsetSizeTrait> ToClassifiedCellListArray<O> for WKTArray<O> {
+    fn to_classified_celllistarray(
+        &self,
+        options: &ToCellsOptions,
+    ) -> Result<ClassifiedCellLists<O>, Error> {
+        positions_to_classified_cells(self.len(), |pos| self.get_as_geo(pos), options)
+    }
+}
+
+/// Convert the geometries at the positions `0..len` to cells, in parallel when the `rayon`
+/// feature is enabled.
+fn positions_to_celllistarray<O, F>(
+    len: usize,
+    get_geometry: F,
+    options: &ToCellsOptions,
+) -> Result<H3ListArray<CellIndex, O>, Error>
+where
+    O: OffsetSizeTrait,
+    F: Fn(usize) -> Result<Option<Geometry>, Error> + Sync,
+{
+    #[cfg(not(feature = "rayon"))]
+    let pos_iter = 0..len;
+
+    #[cfg(feature = "rayon")]
+    let pos_iter = (0..len).into_par_iter();
+
+    let cell_vecs = pos_iter
+        .map(|pos| {
+            get_geometry(pos)?
+                .map(|geom| geometry_to_cells(&geom, options))
+                .transpose()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    cell_vecs_to_h3listarray(cell_vecs)
+}
+
+fn celllistarray_to_cellindexarray<O: OffsetSizeTrait>(
+    celllistarray: H3ListArray<CellIndex, O>,
+    options: &ToCellsOptions,
+) -> Result<CellIndexArray, Error> {
+    let cellindexarray = celllistarray.into_flattened()?;
+
+    if options.compact {
+        cellindexarray.compact()
+    } else {
+        Ok(cellindexarray) // may contain duplicates
     }
 }
@@ -54,14 +54,7 @@ where
     pub(crate) fn from_genericlistarray_unvalidated(
         value: GenericListArray<O>,
     ) -> Result<H3ListArray<IX, O>, Error> {
-        if value.data_type() != &DataType::UInt64 {
-            return Err(Error::NotAUint64Array);
-        }
-
-        Ok(Self {
-            list_array: value,
-            h3index_phantom: PhantomData::<IX>,
-        })
+        genericlistarray_to_h3listarray_unvalidated(value)
     }
 }
 
@@ -176,7 +169,8 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::array::H3ListArrayBuilder;
+    use crate::array::{H3ListArray, H3ListArrayBuilder};
+    use arrow::array::GenericListArray;
     use h3o::{CellIndex, LatLng, Resolution};
 
     #[test]
@@ -212,4 +206,17 @@ mod tests {
         let cells = list.into_flattened().unwrap();
         assert_eq!(cells.len(), 26);
     }
+
+    #[test]
+    fn try_from_genericlistarray() {
+        let cell = LatLng::new(23.4, 12.4).unwrap().to_cell(Resolution::Five);
+
+        let mut builder = H3ListArrayBuilder::<CellIndex>::default();
+        builder.values().append_many(cell.grid_disk::<Vec<_>>(1));
+        builder.append(true);
+        let listarray: GenericListArray<i64> = builder.finish().unwrap().into();
+
+        let list = H3ListArray::<CellIndex>::try_from(listarray).unwrap();
+        assert_eq!(list.len(), 1);
+    }
 }
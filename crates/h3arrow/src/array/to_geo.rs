@@ -1,11 +1,13 @@
+use crate::algorithm::radius::{angle_rads, to_xyz};
 use crate::array::{
     CellIndexArray, DirectedEdgeIndexArray, H3ListArray, PrimitiveArrayH3IndexIter,
     VertexIndexArray,
 };
 use crate::error::Error;
-use geo::{CoordsIter, ToRadians};
-use geo_types::{Coord, Line, LineString, MultiPoint, MultiPolygon, Point, Polygon};
-use h3o::{CellIndex, DirectedEdgeIndex, LatLng, VertexIndex};
+use arrow::error::ArrowError;
+use geo::{BooleanOps, BoundingRect, CoordsIter, MapCoords, ToRadians};
+use geo_types::{Coord, Line, LineString, MultiPoint, MultiPolygon, Point, Polygon, Rect};
+use h3o::{CellIndex, DirectedEdgeIndex, LatLng, Resolution, VertexIndex, EARTH_RADIUS_KM};
 use std::convert::Infallible;
 use std::iter::{repeat, Map, Repeat, Zip};
 
@@ -17,6 +19,47 @@ pub trait IterPolygons {
         Self: 'a;
 
     fn iter_polygons(&self, use_degrees: bool) -> Self::Iter<'_>;
+
+    /// Iterate over the polygons while handling polygons crossing the antimeridian
+    /// as specified by `mode`.
+    fn iter_polygons_antimeridian(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> impl Iterator<Item = Option<Result<MultiPolygon, Self::Error>>> {
+        self.iter_polygons(true).map(move |v| {
+            v.map(|res| {
+                res.map(|poly| {
+                    let mut mp = mode.apply_polygon(poly);
+                    if !use_degrees {
+                        mp.to_radians_in_place();
+                    }
+                    mp
+                })
+            })
+        })
+    }
+
+    /// Like `iter_polygons_antimeridian`, with points inserted along the great circles
+    /// between the vertices of the polygons as specified by `densify`.
+    fn iter_polygons_densified(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+        densify: Densify,
+    ) -> impl Iterator<Item = Option<Result<MultiPolygon, Self::Error>>> {
+        self.iter_polygons(true).map(move |v| {
+            v.map(|res| {
+                res.map(|poly| {
+                    let mut mp = mode.apply_polygon(densify.densify_polygon(&poly));
+                    if !use_degrees {
+                        mp.to_radians_in_place();
+                    }
+                    mp
+                })
+            })
+        })
+    }
 }
 
 impl IterPolygons for CellIndexArray {
@@ -44,6 +87,50 @@ impl IterPolygons for CellIndexArray {
 pub trait ToPolygons {
     type Error;
     fn to_polygons(&self, use_degrees: bool) -> Result<Vec<Option<Polygon>>, Self::Error>;
+
+    /// Polygons with the polygons crossing the antimeridian handled as specified by `mode`.
+    fn to_polygons_antimeridian(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<Vec<Option<MultiPolygon>>, Self::Error> {
+        Ok(self
+            .to_polygons(true)?
+            .into_iter()
+            .map(|poly| {
+                poly.map(|poly| {
+                    let mut mp = mode.apply_polygon(poly);
+                    if !use_degrees {
+                        mp.to_radians_in_place();
+                    }
+                    mp
+                })
+            })
+            .collect())
+    }
+
+    /// Like `to_polygons_antimeridian`, with points inserted along the great circles between
+    /// the vertices of the polygons as specified by `densify`.
+    fn to_polygons_densified(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+        densify: Densify,
+    ) -> Result<Vec<Option<MultiPolygon>>, Self::Error> {
+        Ok(self
+            .to_polygons(true)?
+            .into_iter()
+            .map(|poly| {
+                poly.map(|poly| {
+                    let mut mp = mode.apply_polygon(densify.densify_polygon(&poly));
+                    if !use_degrees {
+                        mp.to_radians_in_place();
+                    }
+                    mp
+                })
+            })
+            .collect())
+    }
 }
 
 impl<T> ToPolygons for T
@@ -178,6 +265,28 @@ impl ToLines for DirectedEdgeIndexArray {
 pub trait ToLineStrings {
     type Error;
     fn to_linestrings(&self, use_degrees: bool) -> Result<Vec<Option<LineString>>, Self::Error>;
+
+    /// Linestrings with points inserted along the great circles between the vertices as
+    /// specified by `densify`.
+    fn to_linestrings_densified(
+        &self,
+        use_degrees: bool,
+        densify: Densify,
+    ) -> Result<Vec<Option<LineString>>, Self::Error> {
+        Ok(self
+            .to_linestrings(true)?
+            .into_iter()
+            .map(|linestring| {
+                linestring.map(|linestring| {
+                    let mut linestring = densify.densify_linestring(&linestring);
+                    if !use_degrees {
+                        linestring.to_radians_in_place();
+                    }
+                    linestring
+                })
+            })
+            .collect())
+    }
 }
 
 impl ToLineStrings for DirectedEdgeIndexArray {
@@ -193,6 +302,45 @@ pub trait ToMultiPolygons {
     type Error;
     type Output;
     fn to_multipolygons(&self, use_degrees: bool) -> Result<Self::Output, Self::Error>;
+
+    /// Dissolved multipolygons with the polygons crossing the antimeridian handled as
+    /// specified by `mode`.
+    fn to_multipolygons_antimeridian(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Self::Output: MapMultiPolygons,
+    {
+        Ok(self.to_multipolygons(true)?.map_multipolygons(|mp| {
+            let mut mp = mode.apply_multipolygon(mp);
+            if !use_degrees {
+                mp.to_radians_in_place();
+            }
+            mp
+        }))
+    }
+
+    /// Like `to_multipolygons_antimeridian`, with points inserted along the great circles
+    /// between the vertices as specified by `densify`.
+    fn to_multipolygons_densified(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+        densify: Densify,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Self::Output: MapMultiPolygons,
+    {
+        Ok(self.to_multipolygons(true)?.map_multipolygons(|mp| {
+            let mut mp = mode.apply_multipolygon(densify.densify_multipolygon(&mp));
+            if !use_degrees {
+                mp.to_radians_in_place();
+            }
+            mp
+        }))
+    }
 }
 
 impl ToMultiPolygons for H3ListArray<CellIndex> {
@@ -228,6 +376,131 @@ impl ToMultiPolygons for CellIndexArray {
     }
 }
 
+/// Outputs of [`ToMultiPolygons`] consisting of multipolygons.
+pub trait MapMultiPolygons {
+    /// Apply `f` to all contained multipolygons.
+    fn map_multipolygons<F>(self, f: F) -> Self
+    where
+        F: FnMut(MultiPolygon) -> MultiPolygon;
+}
+
+impl MapMultiPolygons for MultiPolygon {
+    fn map_multipolygons<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(MultiPolygon) -> MultiPolygon,
+    {
+        f(self)
+    }
+}
+
+impl MapMultiPolygons for Vec<Option<MultiPolygon>> {
+    fn map_multipolygons<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(MultiPolygon) -> MultiPolygon,
+    {
+        self.into_iter().map(|mp| mp.map(&mut f)).collect()
+    }
+}
+
+pub trait ToDescendantPolygons {
+    /// Polygons outlining the union of the descendants of each cell at `resolution`.
+    ///
+    /// Coarse cells do not exactly contain their children, so the boundaries of compacted cells
+    /// leave gaps and overlaps against neighboring cells at finer resolutions. The returned
+    /// polygons match the outlines of the uncompacted cells instead. Cells at `resolution` or
+    /// finer are returned unchanged.
+    fn to_descendant_polygons(
+        &self,
+        resolution: Resolution,
+        use_degrees: bool,
+    ) -> Result<Vec<Option<Polygon>>, Error>;
+
+    /// Like `to_descendant_polygons`, with the polygons crossing the antimeridian handled as
+    /// specified by `mode`.
+    fn to_descendant_polygons_antimeridian(
+        &self,
+        resolution: Resolution,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<Vec<Option<MultiPolygon>>, Error>;
+}
+
+impl ToDescendantPolygons for CellIndexArray {
+    fn to_descendant_polygons(
+        &self,
+        resolution: Resolution,
+        use_degrees: bool,
+    ) -> Result<Vec<Option<Polygon>>, Error> {
+        self.iter()
+            .map(|v| {
+                v.map(|cell| {
+                    let mut poly = descendants_outline(cell, resolution)?;
+                    if !use_degrees {
+                        poly.to_radians_in_place();
+                    }
+                    Ok(poly)
+                })
+                .transpose()
+            })
+            .collect()
+    }
+
+    fn to_descendant_polygons_antimeridian(
+        &self,
+        resolution: Resolution,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<Vec<Option<MultiPolygon>>, Error> {
+        self.iter()
+            .map(|v| {
+                v.map(|cell| {
+                    let mut mp = mode.apply_polygon(descendants_outline(cell, resolution)?);
+                    if !use_degrees {
+                        mp.to_radians_in_place();
+                    }
+                    Ok(mp)
+                })
+                .transpose()
+            })
+            .collect()
+    }
+}
+
+/// The outline of the union of the descendants of `cell` at `resolution` using degrees.
+///
+/// Only the descendants located at the boundary of the union are generated. Descending
+/// level by level, children without any neighbor outside of `cell` are dropped as all of
+/// their descendants are located in the interior of the union as well.
+pub fn descendants_outline(cell: CellIndex, resolution: Resolution) -> Result<Polygon, Error> {
+    let cell_resolution = cell.resolution();
+    if resolution <= cell_resolution {
+        return Ok(Polygon::new(LineString::from(cell.boundary()), vec![]));
+    }
+
+    let mut boundary_cells = vec![cell];
+    for r in Resolution::range(cell_resolution, resolution).skip(1) {
+        boundary_cells = boundary_cells
+            .iter()
+            .flat_map(|c| c.children(r))
+            .filter(|child| {
+                child
+                    .grid_disk::<Vec<_>>(1)
+                    .iter()
+                    .any(|neighbor| neighbor.parent(cell_resolution) != Some(cell))
+            })
+            .collect();
+    }
+
+    // the boundary cells form a band enclosing all other descendants, so its exterior ring
+    // is the outline of the union.
+    let band = h3o::geom::dissolve(boundary_cells)?;
+    Ok(band
+        .into_iter()
+        .max_by_key(|polygon| polygon.exterior().0.len())
+        .map(|polygon| Polygon::new(polygon.exterior().clone(), vec![]))
+        .unwrap_or_else(|| Polygon::new(LineString::new(vec![]), vec![])))
+}
+
 /// used as base for the algorithms of the `geo` crate
 pub(crate) fn directededgeindexarray_to_multipoint(array: &DirectedEdgeIndexArray) -> MultiPoint {
     MultiPoint::new(
@@ -263,3 +536,503 @@ pub(crate) fn cellindexarray_to_multipolygon(array: &CellIndexArray) -> MultiPol
             .collect(),
     )
 }
+
+/// Handling of polygons crossing the antimeridian.
+///
+/// Polygons are built from the coordinates of the cell boundaries, so polygons crossing
+/// the antimeridian span the whole globe when interpreted in a planar coordinate system.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AntimeridianMode {
+    /// Keep the coordinates unchanged.
+    #[default]
+    Keep,
+
+    /// Shift the longitudes of polygons crossing the antimeridian into a continuous range. The
+    /// longitudes of these polygons may exceed 180°.
+    Shift,
+
+    /// Split polygons crossing the antimeridian into parts on both sides of the antimeridian.
+    Split,
+}
+
+impl AntimeridianMode {
+    /// Apply the mode to a polygon using degrees.
+    ///
+    /// Polygons enclosing a pole are extended to the pole in the `Shift` and `Split` modes.
+    pub fn apply_polygon(self, polygon: Polygon) -> MultiPolygon {
+        match self {
+            Self::Keep => MultiPolygon::new(vec![polygon]),
+            Self::Shift => MultiPolygon::new(vec![unwrap_polygon(&polygon)]),
+            Self::Split => split_unwrapped_polygon(unwrap_polygon(&polygon)),
+        }
+    }
+
+    /// Apply the mode to all polygons of a multipolygon using degrees.
+    pub fn apply_multipolygon(self, multipolygon: MultiPolygon) -> MultiPolygon {
+        match self {
+            Self::Keep => multipolygon,
+            _ => MultiPolygon::new(
+                multipolygon
+                    .into_iter()
+                    .flat_map(|polygon| self.apply_polygon(polygon))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Densification of geometries along great circles.
+///
+/// The edges of cells are arcs of great circles, while the boundaries returned by `h3o` only
+/// consist of the vertices of the cells. Inserting points along the great circles between the
+/// vertices keeps neighboring cells tiling without gaps after projecting them to a planar
+/// coordinate system.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Densify {
+    max_angle_rads: f64,
+}
+
+impl Densify {
+    /// Limit the angle spanned by the segments to `max_angle_rads`.
+    pub fn max_angle_rads(max_angle_rads: f64) -> Result<Self, Error> {
+        if !(max_angle_rads.is_finite() && max_angle_rads > 0.0) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "the maximum segment angle must be a positive number, found {max_angle_rads}"
+            ))
+            .into());
+        }
+        Ok(Self { max_angle_rads })
+    }
+
+    /// Limit the length of the segments to `max_length_m` meters.
+    pub fn max_length_m(max_length_m: f64) -> Result<Self, Error> {
+        if !(max_length_m.is_finite() && max_length_m > 0.0) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "the maximum segment length must be a positive number, found {max_length_m}"
+            ))
+            .into());
+        }
+        Self::max_angle_rads(max_length_m / (EARTH_RADIUS_KM * 1000.0))
+    }
+
+    /// Densify a linestring using degrees.
+    pub fn densify_linestring(&self, linestring: &LineString) -> LineString {
+        let mut coords = Vec::with_capacity(linestring.0.len());
+        for line in linestring.lines() {
+            coords.push(line.start);
+
+            // segments with invalid coordinates are kept as they are
+            let (Ok(a), Ok(b)) = (LatLng::try_from(line.start), LatLng::try_from(line.end)) else {
+                continue;
+            };
+            let (a, b) = (to_xyz(a), to_xyz(b));
+            let angle = angle_rads(&a, &b);
+            let num_segments = (angle / self.max_angle_rads).ceil();
+            if num_segments < 2.0 || angle.sin() < f64::EPSILON {
+                continue;
+            }
+            for i in 1..(num_segments as usize) {
+                // spherical linear interpolation between the endpoints
+                let f = i as f64 / num_segments;
+                let wa = ((1.0 - f) * angle).sin() / angle.sin();
+                let wb = (f * angle).sin() / angle.sin();
+                coords.push(xyz_to_coord([
+                    wa * a[0] + wb * b[0],
+                    wa * a[1] + wb * b[1],
+                    wa * a[2] + wb * b[2],
+                ]));
+            }
+        }
+        if let Some(last) = linestring.0.last() {
+            coords.push(*last);
+        }
+        LineString::new(coords)
+    }
+
+    /// Densify all rings of a polygon using degrees.
+    pub fn densify_polygon(&self, polygon: &Polygon) -> Polygon {
+        Polygon::new(
+            self.densify_linestring(polygon.exterior()),
+            polygon
+                .interiors()
+                .iter()
+                .map(|ring| self.densify_linestring(ring))
+                .collect(),
+        )
+    }
+
+    /// Densify all polygons of a multipolygon using degrees.
+    pub fn densify_multipolygon(&self, multipolygon: &MultiPolygon) -> MultiPolygon {
+        MultiPolygon::new(
+            multipolygon
+                .iter()
+                .map(|polygon| self.densify_polygon(polygon))
+                .collect(),
+        )
+    }
+}
+
+fn xyz_to_coord(v: [f64; 3]) -> Coord {
+    Coord {
+        x: v[1].atan2(v[0]).to_degrees(),
+        y: v[2].atan2(v[0].hypot(v[1])).to_degrees(),
+    }
+}
+
+/// Unwrap the longitudes of a ring, so no consecutive coordinates are more than 180° apart.
+///
+/// Returns the unwrapped coordinates and a flag indicating if the ring encloses a pole.
+fn unwrap_ring(ring: &LineString) -> (Vec<Coord>, bool) {
+    let mut offset = 0.0;
+    let mut previous_x: Option<f64> = None;
+    let coords: Vec<Coord> = ring
+        .coords()
+        .map(|c| {
+            if let Some(previous_x) = previous_x {
+                let delta = c.x - previous_x;
+                if delta > 180.0 {
+                    offset -= 360.0;
+                } else if delta < -180.0 {
+                    offset += 360.0;
+                }
+            }
+            previous_x = Some(c.x);
+            Coord {
+                x: c.x + offset,
+                y: c.y,
+            }
+        })
+        .collect();
+
+    // a closed ring around a pole does not return to its starting longitude
+    let encloses_pole = match (coords.first(), coords.last()) {
+        (Some(first), Some(last)) => (last.x - first.x).abs() > 180.0,
+        _ => false,
+    };
+    (coords, encloses_pole)
+}
+
+/// shift the coordinates by multiples of 360° so the smallest longitude is within [-180, 180)
+fn normalize_longitudes(coords: &mut [Coord]) {
+    let min_x = coords.iter().map(|c| c.x).fold(f64::INFINITY, f64::min);
+    if min_x.is_finite() {
+        let shift = -((min_x + 180.0) / 360.0).floor() * 360.0;
+        if shift != 0.0 {
+            coords.iter_mut().for_each(|c| c.x += shift);
+        }
+    }
+}
+
+fn unwrap_polygon(polygon: &Polygon) -> Polygon {
+    let (mut exterior, encloses_pole) = unwrap_ring(polygon.exterior());
+    if encloses_pole {
+        if let (Some(first), Some(last)) = (exterior.first().copied(), exterior.last().copied()) {
+            let pole_lat = if first.y >= 0.0 { 90.0 } else { -90.0 };
+            exterior.pop();
+            exterior.extend([
+                last,
+                Coord {
+                    x: last.x,
+                    y: pole_lat,
+                },
+                Coord {
+                    x: first.x,
+                    y: pole_lat,
+                },
+                first,
+            ]);
+        }
+    }
+    normalize_longitudes(&mut exterior);
+    let exterior = LineString::from(exterior);
+
+    let exterior_center_x = exterior
+        .bounding_rect()
+        .map(|rect| rect.center().x)
+        .unwrap_or(0.0);
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .map(|interior| {
+            let (mut coords, _) = unwrap_ring(interior);
+            if let Some(center_x) = LineString::from(coords.clone())
+                .bounding_rect()
+                .map(|rect| rect.center().x)
+            {
+                // place the hole on the same side of the antimeridian as the exterior
+                let shift = ((exterior_center_x - center_x) / 360.0).round() * 360.0;
+                coords.iter_mut().for_each(|c| c.x += shift);
+            }
+            LineString::from(coords)
+        })
+        .collect();
+    Polygon::new(exterior, interiors)
+}
+
+const ANTIMERIDIAN_SNAP_DISTANCE: f64 = 1e-6;
+
+fn split_unwrapped_polygon(polygon: Polygon) -> MultiPolygon {
+    let crosses = polygon
+        .bounding_rect()
+        .map(|rect| rect.max().x > 180.0)
+        .unwrap_or(false);
+    if !crosses {
+        return MultiPolygon::new(vec![polygon]);
+    }
+
+    let western = Rect::new((-180.0, -90.0), (180.0, 90.0)).to_polygon();
+    let eastern = Rect::new((180.0, -90.0), (540.0, 90.0)).to_polygon();
+    let mut parts = polygon.intersection(&western);
+    parts.0.extend(
+        polygon
+            .intersection(&eastern)
+            .map_coords(|c| Coord {
+                x: c.x - 360.0,
+                y: c.y,
+            })
+            .0,
+    );
+
+    // the boolean operations do not preserve the exact coordinates on the antimeridian
+    parts.map_coords(|c| Coord {
+        x: if (c.x.abs() - 180.0).abs() < ANTIMERIDIAN_SNAP_DISTANCE {
+            180.0_f64.copysign(c.x)
+        } else {
+            c.x
+        },
+        y: c.y,
+    })
+}
+
+/// The bounding rect of a polygon with the longitudes unwrapped at the antimeridian.
+pub(crate) fn unwrapped_polygon_bounding_rect(polygon: &Polygon) -> Option<Rect> {
+    unwrap_polygon(polygon).bounding_rect()
+}
+
+/// The bounding rect of a line with the longitudes unwrapped at the antimeridian.
+pub(crate) fn unwrapped_line_bounding_rect(line: &Line) -> Option<Rect> {
+    let (mut coords, _) = unwrap_ring(&LineString::from(*line));
+    normalize_longitudes(&mut coords);
+    LineString::from(coords).bounding_rect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::radius::{angle_rads, arc_distance_rads, to_xyz};
+    use crate::algorithm::CompactOp;
+    use crate::array::to_geo::{
+        AntimeridianMode, Densify, ToDescendantPolygons, ToLineStrings, ToMultiPolygons, ToPolygons,
+    };
+    use crate::array::{CellIndexArray, DirectedEdgeIndexArray};
+    use geo::{Area, BoundingRect};
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    #[test]
+    fn antimeridian_shift() {
+        let cell = LatLng::new(0.0, 180.0).unwrap().to_cell(Resolution::Three);
+        let cells = CellIndexArray::from(vec![cell]);
+
+        let polygon = cells.to_polygons(true).unwrap()[0].clone().unwrap();
+        let rect = polygon.bounding_rect().unwrap();
+        assert!(rect.width() > 300.0);
+
+        let shifted = cells
+            .to_polygons_antimeridian(true, AntimeridianMode::Shift)
+            .unwrap()[0]
+            .clone()
+            .unwrap();
+        assert_eq!(shifted.0.len(), 1);
+        let rect = shifted.bounding_rect().unwrap();
+        assert!(rect.width() < 5.0);
+        assert!(rect.min().x < 180.0);
+        assert!(rect.max().x > 180.0);
+    }
+
+    #[test]
+    fn antimeridian_split() {
+        let cell = LatLng::new(0.0, 180.0).unwrap().to_cell(Resolution::Three);
+        let cells = CellIndexArray::from(vec![cell]);
+
+        let shifted = cells
+            .to_polygons_antimeridian(true, AntimeridianMode::Shift)
+            .unwrap()[0]
+            .clone()
+            .unwrap();
+        let split = cells
+            .to_polygons_antimeridian(true, AntimeridianMode::Split)
+            .unwrap()[0]
+            .clone()
+            .unwrap();
+        assert_eq!(split.0.len(), 2);
+        let rect = split.bounding_rect().unwrap();
+        assert!(rect.min().x >= -180.0);
+        assert!(rect.max().x <= 180.0);
+        assert!((split.unsigned_area() / shifted.unsigned_area() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn antimeridian_pole() {
+        let cell = LatLng::new(90.0, 0.0).unwrap().to_cell(Resolution::One);
+        let cells = CellIndexArray::from(vec![cell]);
+
+        let split = cells
+            .to_polygons_antimeridian(true, AntimeridianMode::Split)
+            .unwrap()[0]
+            .clone()
+            .unwrap();
+        let rect = split.bounding_rect().unwrap();
+        assert_eq!(rect.max().y, 90.0);
+        assert_eq!(rect.min().x, -180.0);
+        assert_eq!(rect.max().x, 180.0);
+    }
+
+    #[test]
+    fn antimeridian_keep() {
+        let cell = LatLng::new(10.0, 20.0).unwrap().to_cell(Resolution::Three);
+        let cells = CellIndexArray::from(vec![cell]);
+
+        let polygon = cells.to_polygons(true).unwrap()[0].clone().unwrap();
+        for mode in [
+            AntimeridianMode::Keep,
+            AntimeridianMode::Shift,
+            AntimeridianMode::Split,
+        ] {
+            let mp = cells.to_polygons_antimeridian(true, mode).unwrap()[0]
+                .clone()
+                .unwrap();
+            assert_eq!(mp.0, vec![polygon.clone()]);
+        }
+    }
+
+    #[test]
+    fn densify_polygons() {
+        let cell = LatLng::new(50.0, 10.0).unwrap().to_cell(Resolution::Zero);
+        let cells = CellIndexArray::from(vec![cell]);
+        let densify = Densify::max_length_m(10_000.0).unwrap();
+
+        let polygon = cells.to_polygons(true).unwrap()[0].clone().unwrap();
+        let densified = cells
+            .to_polygons_densified(true, AntimeridianMode::Keep, densify)
+            .unwrap()[0]
+            .clone()
+            .unwrap();
+        assert_eq!(densified.0.len(), 1);
+        let densified = &densified.0[0];
+        assert!(densified.exterior().0.len() > 10 * polygon.exterior().0.len());
+
+        // all points are located on the great circle arcs between the vertices
+        let vertices = polygon
+            .exterior()
+            .0
+            .iter()
+            .map(|c| to_xyz(LatLng::try_from(*c).unwrap()))
+            .collect::<Vec<_>>();
+        for c in densified.exterior().0.iter() {
+            let p = to_xyz(LatLng::try_from(*c).unwrap());
+            let distance = vertices
+                .windows(2)
+                .map(|w| arc_distance_rads(&p, &w[0], &w[1]))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance < 1e-12);
+        }
+
+        let max_angle = 10_000.0 / (h3o::EARTH_RADIUS_KM * 1000.0);
+        for w in densified.exterior().0.windows(2) {
+            let (a, b) = (
+                to_xyz(LatLng::try_from(w[0]).unwrap()),
+                to_xyz(LatLng::try_from(w[1]).unwrap()),
+            );
+            assert!(angle_rads(&a, &b) <= max_angle + 1e-12);
+        }
+    }
+
+    #[test]
+    fn densify_linestrings() {
+        let cell = LatLng::new(50.0, 10.0).unwrap().to_cell(Resolution::Two);
+        let edges = DirectedEdgeIndexArray::from(cell.edges().collect::<Vec<_>>());
+        let densify = Densify::max_angle_rads(0.1f64.to_radians()).unwrap();
+
+        let linestrings = edges.to_linestrings(true).unwrap();
+        let densified = edges.to_linestrings_densified(true, densify).unwrap();
+        let densified_radians = edges.to_linestrings_densified(false, densify).unwrap();
+        for ((ls, dls), dls_rads) in linestrings
+            .iter()
+            .zip(densified.iter())
+            .zip(densified_radians.iter())
+        {
+            let (ls, dls, dls_rads) = (
+                ls.as_ref().unwrap(),
+                dls.as_ref().unwrap(),
+                dls_rads.as_ref().unwrap(),
+            );
+            assert!(dls.0.len() > ls.0.len());
+            assert_eq!(dls.0.first(), ls.0.first());
+            assert_eq!(dls.0.last(), ls.0.last());
+            assert_eq!(dls.0.len(), dls_rads.0.len());
+            assert!((dls_rads.0[1].x - dls.0[1].x.to_radians()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn densify_antimeridian() {
+        let cell = LatLng::new(0.0, 180.0).unwrap().to_cell(Resolution::One);
+        let cells = CellIndexArray::from(vec![cell]);
+        let densify = Densify::max_length_m(5_000.0).unwrap();
+
+        let split = cells
+            .to_polygons_antimeridian(true, AntimeridianMode::Split)
+            .unwrap()[0]
+            .clone()
+            .unwrap();
+        let densified = cells
+            .to_polygons_densified(true, AntimeridianMode::Split, densify)
+            .unwrap()[0]
+            .clone()
+            .unwrap();
+        assert_eq!(densified.0.len(), 2);
+        let rect = densified.bounding_rect().unwrap();
+        assert!(rect.min().x >= -180.0);
+        assert!(rect.max().x <= 180.0);
+        assert!((densified.unsigned_area() / split.unsigned_area() - 1.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn densify_invalid() {
+        assert!(Densify::max_length_m(0.0).is_err());
+        assert!(Densify::max_length_m(-1.0).is_err());
+        assert!(Densify::max_angle_rads(f64::NAN).is_err());
+        assert!(Densify::max_angle_rads(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn descendant_polygons() {
+        let cell = LatLng::new(50.0, 10.0).unwrap().to_cell(Resolution::Three);
+        let pentagon = CellIndex::base_cells()
+            .find(|c| c.is_pentagon())
+            .unwrap()
+            .center_child(Resolution::Two)
+            .unwrap();
+        let cells = CellIndexArray::from(vec![cell, pentagon]);
+
+        let outlines = cells.to_descendant_polygons(Resolution::Six, true).unwrap();
+        assert_eq!(outlines.len(), 2);
+        for (cell, outline) in cells.iter().zip(outlines) {
+            let outline = outline.unwrap();
+            let dissolved = CellIndexArray::from(vec![cell.unwrap()])
+                .uncompact(Resolution::Six)
+                .to_multipolygons(true)
+                .unwrap();
+            assert_eq!(dissolved.0.len(), 1);
+            assert!(dissolved.0[0].interiors().is_empty());
+            assert_eq!(
+                outline.exterior().0.len(),
+                dissolved.0[0].exterior().0.len()
+            );
+            assert!((outline.unsigned_area() / dissolved.unsigned_area() - 1.0).abs() < 1e-9);
+        }
+
+        // cells at the target resolution or finer are unchanged
+        let unchanged = cells.to_descendant_polygons(Resolution::Two, true).unwrap();
+        assert_eq!(unchanged, cells.to_polygons(true).unwrap());
+    }
+}
@@ -1,13 +1,14 @@
 use crate::array::to_geo::{
-    IterLines, IterPoints, IterPolygons, ToLineStrings, ToPoints, ToPolygons,
+    AntimeridianMode, Densify, IterLines, IterPoints, IterPolygons, ToLineStrings, ToMultiPolygons,
+    ToPoints, ToPolygons,
 };
 use crate::array::{H3Array, H3IndexArrayValue};
 use arrow::array::{Array, OffsetSizeTrait};
 use geo::point;
-use geo_types::LineString;
+use geo_types::{LineString, MultiPolygon};
 use geoarrow::array::{
-    LineStringArray, LineStringBuilder, PointArray, PointBuilder, PolygonArray, PolygonBuilder,
-    WKBArray, WKBBuilder, WKBCapacity,
+    LineStringArray, LineStringBuilder, MultiPolygonArray, MultiPolygonBuilder, PointArray,
+    PointBuilder, PolygonArray, PolygonBuilder, WKBArray, WKBBuilder, WKBCapacity,
 };
 use geoarrow::datatypes::Dimension;
 
@@ -17,6 +18,30 @@ pub trait ToGeoArrowPolygons {
         &self,
         use_degrees: bool,
     ) -> Result<PolygonArray, Self::Error>;
+
+    /// Like `to_geoarrow_polygons`, with points inserted along the great circles between the
+    /// vertices as specified by `densify`.
+    fn to_geoarrow_polygons_densified<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        densify: Densify,
+    ) -> Result<PolygonArray, <Self as ToGeoArrowPolygons>::Error>
+    where
+        Self: ToPolygons<Error = <Self as ToGeoArrowPolygons>::Error>,
+    {
+        let polygons = self
+            .to_polygons_densified(use_degrees, AntimeridianMode::Keep, densify)?
+            .into_iter()
+            .map(|mp| mp.and_then(|mp| mp.0.into_iter().next()))
+            .collect::<Vec<_>>();
+        Ok(PolygonBuilder::from_nullable_polygons(
+            &polygons,
+            Dimension::XY,
+            Default::default(),
+            Default::default(),
+        )
+        .into())
+    }
 }
 
 impl<T> ToGeoArrowPolygons for T
@@ -39,6 +64,56 @@ where
     }
 }
 
+pub trait ToGeoArrowMultiPolygons {
+    type Error;
+    fn to_geoarrow_multipolygons(
+        &self,
+        use_degrees: bool,
+    ) -> Result<MultiPolygonArray, Self::Error>;
+
+    /// Like `to_geoarrow_multipolygons`, with points inserted along the great circles between
+    /// the vertices as specified by `densify`.
+    fn to_geoarrow_multipolygons_densified(
+        &self,
+        use_degrees: bool,
+        densify: Densify,
+    ) -> Result<MultiPolygonArray, <Self as ToGeoArrowMultiPolygons>::Error>
+    where
+        Self: ToMultiPolygons<
+            Output = Vec<Option<MultiPolygon>>,
+            Error = <Self as ToGeoArrowMultiPolygons>::Error,
+        >,
+    {
+        Ok(MultiPolygonBuilder::from_nullable_multi_polygons(
+            &self.to_multipolygons_densified(use_degrees, AntimeridianMode::Keep, densify)?,
+            Dimension::XY,
+            Default::default(),
+            Default::default(),
+        )
+        .into())
+    }
+}
+
+impl<T> ToGeoArrowMultiPolygons for T
+where
+    T: ToMultiPolygons<Output = Vec<Option<MultiPolygon>>>,
+{
+    type Error = T::Error;
+
+    fn to_geoarrow_multipolygons(
+        &self,
+        use_degrees: bool,
+    ) -> Result<MultiPolygonArray, Self::Error> {
+        Ok(MultiPolygonBuilder::from_nullable_multi_polygons(
+            &self.to_multipolygons(use_degrees)?,
+            Dimension::XY,
+            Default::default(),
+            Default::default(),
+        )
+        .into())
+    }
+}
+
 pub trait ToGeoArrowPoints {
     type Error;
     fn to_geoarrow_points(&self, use_degrees: bool) -> Result<PointArray, Self::Error>;
@@ -66,6 +141,25 @@ pub trait ToGeoArrowLineStrings {
         &self,
         use_degrees: bool,
     ) -> Result<LineStringArray, Self::Error>;
+
+    /// Like `to_geoarrow_lines`, with points inserted along the great circles between the
+    /// vertices as specified by `densify`.
+    fn to_geoarrow_lines_densified<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        densify: Densify,
+    ) -> Result<LineStringArray, <Self as ToGeoArrowLineStrings>::Error>
+    where
+        Self: ToLineStrings<Error = <Self as ToGeoArrowLineStrings>::Error>,
+    {
+        Ok(LineStringBuilder::from_nullable_line_strings(
+            &self.to_linestrings_densified(use_degrees, densify)?,
+            Dimension::XY,
+            Default::default(),
+            Default::default(),
+        )
+        .into())
+    }
 }
 
 impl<T> ToGeoArrowLineStrings for T
@@ -93,6 +187,53 @@ pub trait ToWKBPolygons {
         &self,
         use_degrees: bool,
     ) -> Result<WKBArray<O>, Self::Error>;
+
+    /// Like `to_wkb_polygons`, with the polygons crossing the antimeridian handled as specified
+    /// by `mode`. The `Split` mode generates multipolygons for all cells.
+    fn to_wkb_polygons_antimeridian<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<WKBArray<O>, <Self as ToWKBPolygons>::Error>
+    where
+        Self: IterPolygons<Error = <Self as ToWKBPolygons>::Error>,
+    {
+        if mode == AntimeridianMode::Keep {
+            return self.to_wkb_polygons(use_degrees);
+        }
+
+        let mut builder = WKBBuilder::with_capacity(WKBCapacity::new_empty());
+        for mp in self.iter_polygons_antimeridian(use_degrees, mode) {
+            let mp = mp.transpose()?;
+            match (mode, mp) {
+                (AntimeridianMode::Split, mp) => builder.push_multi_polygon(mp.as_ref()),
+                (_, mp) => builder.push_polygon(mp.and_then(|mp| mp.0.into_iter().next()).as_ref()),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Like `to_wkb_polygons_antimeridian`, with points inserted along the great circles
+    /// between the vertices as specified by `densify`.
+    fn to_wkb_polygons_densified<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+        densify: Densify,
+    ) -> Result<WKBArray<O>, <Self as ToWKBPolygons>::Error>
+    where
+        Self: IterPolygons<Error = <Self as ToWKBPolygons>::Error>,
+    {
+        let mut builder = WKBBuilder::with_capacity(WKBCapacity::new_empty());
+        for mp in self.iter_polygons_densified(use_degrees, mode, densify) {
+            let mp = mp.transpose()?;
+            match (mode, mp) {
+                (AntimeridianMode::Split, mp) => builder.push_multi_polygon(mp.as_ref()),
+                (_, mp) => builder.push_polygon(mp.and_then(|mp| mp.0.into_iter().next()).as_ref()),
+            }
+        }
+        Ok(builder.finish())
+    }
 }
 
 impl<T> ToWKBPolygons for H3Array<T>
@@ -139,12 +280,110 @@ where
     }
 }
 
+pub trait ToWKBMultiPolygons {
+    type Error;
+
+    /// Dissolve the cells of each list into a multipolygon and write it as WKB. The
+    /// geometries match the order of the lists, null lists result in null geometries.
+    fn to_wkb_multipolygons<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+    ) -> Result<WKBArray<O>, Self::Error>;
+
+    /// Like `to_wkb_multipolygons`, with the polygons crossing the antimeridian handled as
+    /// specified by `mode`.
+    fn to_wkb_multipolygons_antimeridian<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<WKBArray<O>, Self::Error>;
+
+    /// Like `to_wkb_multipolygons_antimeridian`, with points inserted along the great circles
+    /// between the vertices as specified by `densify`.
+    fn to_wkb_multipolygons_densified<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+        densify: Densify,
+    ) -> Result<WKBArray<O>, <Self as ToWKBMultiPolygons>::Error>
+    where
+        Self: ToMultiPolygons<
+            Output = Vec<Option<MultiPolygon>>,
+            Error = <Self as ToWKBMultiPolygons>::Error,
+        >,
+    {
+        Ok(multipolygons_to_wkb(&self.to_multipolygons_densified(
+            use_degrees,
+            mode,
+            densify,
+        )?))
+    }
+}
+
+impl<T> ToWKBMultiPolygons for T
+where
+    T: ToMultiPolygons<Output = Vec<Option<MultiPolygon>>>,
+{
+    type Error = T::Error;
+
+    fn to_wkb_multipolygons<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+    ) -> Result<WKBArray<O>, Self::Error> {
+        Ok(multipolygons_to_wkb(&self.to_multipolygons(use_degrees)?))
+    }
+
+    fn to_wkb_multipolygons_antimeridian<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        mode: AntimeridianMode,
+    ) -> Result<WKBArray<O>, Self::Error> {
+        Ok(multipolygons_to_wkb(
+            &self.to_multipolygons_antimeridian(use_degrees, mode)?,
+        ))
+    }
+}
+
+fn multipolygons_to_wkb<O: OffsetSizeTrait>(multipolygons: &[Option<MultiPolygon>]) -> WKBArray<O> {
+    let mut capacity = WKBCapacity::new_empty();
+    for mp in multipolygons {
+        capacity.add_multi_polygon(mp.as_ref());
+    }
+    let mut builder = WKBBuilder::with_capacity(capacity);
+    for mp in multipolygons {
+        builder.push_multi_polygon(mp.as_ref());
+    }
+    builder.finish()
+}
+
 pub trait ToWKBLineStrings {
     type Error;
     fn to_wkb_linestrings<O: OffsetSizeTrait>(
         &self,
         use_degrees: bool,
     ) -> Result<WKBArray<O>, Self::Error>;
+
+    /// Like `to_wkb_linestrings`, with points inserted along the great circles between the
+    /// vertices as specified by `densify`.
+    fn to_wkb_linestrings_densified<O: OffsetSizeTrait>(
+        &self,
+        use_degrees: bool,
+        densify: Densify,
+    ) -> Result<WKBArray<O>, <Self as ToWKBLineStrings>::Error>
+    where
+        Self: ToLineStrings<Error = <Self as ToWKBLineStrings>::Error>,
+    {
+        let linestrings = self.to_linestrings_densified(use_degrees, densify)?;
+        let mut capacity = WKBCapacity::new_empty();
+        for linestring in linestrings.iter() {
+            capacity.add_line_string(linestring.as_ref());
+        }
+        let mut builder = WKBBuilder::with_capacity(capacity);
+        for linestring in linestrings.iter() {
+            builder.push_line_string(linestring.as_ref());
+        }
+        Ok(builder.finish())
+    }
 }
 
 impl<T> ToWKBLineStrings for H3Array<T>
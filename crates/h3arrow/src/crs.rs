@@ -0,0 +1,435 @@
+//! Transformation of coordinates between coordinate reference systems and WGS84.
+//!
+//! H3 is based on WGS84 longitude/latitude coordinates. Geometries using a projected
+//! coordinate reference system can be reprojected using the built-in [`Crs`] transformations
+//! for Web Mercator and the UTM zones, other systems can be supported by implementing
+//! [`CoordinateTransform`].
+use std::cell::{Cell, RefCell};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use geo::MapCoordsInPlace;
+use geo_types::{Coord, Geometry};
+
+use crate::error::Error;
+
+/// Transformation between a coordinate reference system and WGS84 longitude/latitude
+/// coordinates in degrees, with `x` being the longitude and `y` the latitude.
+pub trait CoordinateTransform: Debug + Send + Sync {
+    /// Transform coordinates of the coordinate reference system to WGS84 in place.
+    fn transform_to_wgs84(&self, coords: &mut [Coord]) -> Result<(), Error>;
+
+    /// Transform WGS84 coordinates to the coordinate reference system in place.
+    fn transform_from_wgs84(&self, coords: &mut [Coord]) -> Result<(), Error>;
+}
+
+/// The coordinate reference systems supported without any external dependencies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Crs {
+    /// WGS84 longitude/latitude in degrees (EPSG:4326).
+    Wgs84,
+
+    /// Web Mercator, also known as Pseudo-Mercator (EPSG:3857).
+    WebMercator,
+
+    /// Universal Transverse Mercator on the WGS84 ellipsoid (EPSG:326xx for the northern and
+    /// EPSG:327xx for the southern hemisphere).
+    Utm { zone: u8, north: bool },
+}
+
+/// The radius of the sphere used by Web Mercator in meters.
+const WEB_MERCATOR_RADIUS: f64 = 6_378_137.0;
+
+/// The latitude at which Web Mercator becomes a square.
+const WEB_MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// The semi-major axis of the WGS84 ellipsoid in meters.
+pub(crate) const WGS84_A: f64 = 6_378_137.0;
+
+/// The flattening of the WGS84 ellipsoid.
+pub(crate) const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+const UTM_FALSE_NORTHING_SOUTH: f64 = 10_000_000.0;
+
+impl Crs {
+    /// The coordinate reference system of an EPSG code.
+    pub fn from_epsg(code: u32) -> Result<Self, Error> {
+        match code {
+            4326 => Ok(Self::Wgs84),
+            3857 | 900913 => Ok(Self::WebMercator),
+            32601..=32660 => Ok(Self::Utm {
+                zone: (code - 32600) as u8,
+                north: true,
+            }),
+            32701..=32760 => Ok(Self::Utm {
+                zone: (code - 32700) as u8,
+                north: false,
+            }),
+            _ => Err(Error::UnsupportedCrs(format!("EPSG:{code}"))),
+        }
+    }
+
+    /// The EPSG code of the coordinate reference system.
+    pub fn epsg(&self) -> u32 {
+        match self {
+            Self::Wgs84 => 4326,
+            Self::WebMercator => 3857,
+            Self::Utm { zone, north: true } => 32600 + *zone as u32,
+            Self::Utm { zone, north: false } => 32700 + *zone as u32,
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            Self::Utm { zone, .. } if !(1..=60).contains(zone) => Err(Error::UnsupportedCrs(
+                format!("UTM zone {zone} is not within 1 to 60"),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Crs {
+    type Err = Error;
+
+    /// Parses EPSG codes in the form of `EPSG:3857`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .split_once(':')
+            .filter(|(authority, _)| authority.eq_ignore_ascii_case("epsg"))
+            .and_then(|(_, code)| code.trim().parse::<u32>().ok())
+            .ok_or_else(|| Error::UnsupportedCrs(s.to_string()))
+            .and_then(Self::from_epsg)
+    }
+}
+
+impl CoordinateTransform for Crs {
+    fn transform_to_wgs84(&self, coords: &mut [Coord]) -> Result<(), Error> {
+        self.validate()?;
+        match self {
+            Self::Wgs84 => {}
+            Self::WebMercator => coords.iter_mut().for_each(|c| {
+                *c = Coord {
+                    x: (c.x / WEB_MERCATOR_RADIUS).to_degrees(),
+                    y: (2.0 * (c.y / WEB_MERCATOR_RADIUS).exp().atan() - FRAC_PI_2).to_degrees(),
+                }
+            }),
+            Self::Utm { zone, north } => {
+                let tm = TransverseMercator::utm(*zone, *north);
+                coords.iter_mut().for_each(|c| *c = tm.inverse(*c));
+            }
+        }
+        Ok(())
+    }
+
+    fn transform_from_wgs84(&self, coords: &mut [Coord]) -> Result<(), Error> {
+        self.validate()?;
+        match self {
+            Self::Wgs84 => {}
+            Self::WebMercator => coords.iter_mut().for_each(|c| {
+                // the poles are located at infinity, so the latitudes are clamped to the
+                // bounds of the projection.
+                let lat =
+                    c.y.clamp(-WEB_MERCATOR_MAX_LATITUDE, WEB_MERCATOR_MAX_LATITUDE)
+                        .to_radians();
+                *c = Coord {
+                    x: c.x.to_radians() * WEB_MERCATOR_RADIUS,
+                    y: (FRAC_PI_4 + lat / 2.0).tan().ln() * WEB_MERCATOR_RADIUS,
+                }
+            }),
+            Self::Utm { zone, north } => {
+                let tm = TransverseMercator::utm(*zone, *north);
+                coords.iter_mut().for_each(|c| *c = tm.forward(*c));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Transverse Mercator projection of the WGS84 ellipsoid using the series of Krüger to the
+/// fourth order of the third flattening. This is accurate to well below a millimeter within
+/// the UTM zones.
+struct TransverseMercator {
+    central_meridian: f64,
+    false_northing: f64,
+    n: f64,
+    /// the rectifying radius scaled by the scale factor
+    k0_a: f64,
+    alpha: [f64; 4],
+    beta: [f64; 4],
+    delta: [f64; 4],
+}
+
+impl TransverseMercator {
+    fn utm(zone: u8, north: bool) -> Self {
+        let n = WGS84_F / (2.0 - WGS84_F);
+        let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+        Self {
+            central_meridian: (zone as f64 * 6.0 - 183.0).to_radians(),
+            false_northing: if north { 0.0 } else { UTM_FALSE_NORTHING_SOUTH },
+            n,
+            k0_a: UTM_SCALE_FACTOR * WGS84_A / (1.0 + n) * (1.0 + n2 / 4.0 + n4 / 64.0),
+            alpha: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0 + 41.0 * n4 / 180.0,
+                13.0 * n2 / 48.0 - 3.0 * n3 / 5.0 + 557.0 * n4 / 1440.0,
+                61.0 * n3 / 240.0 - 103.0 * n4 / 140.0,
+                49561.0 * n4 / 161280.0,
+            ],
+            beta: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0 - n4 / 360.0,
+                n2 / 48.0 + n3 / 15.0 - 437.0 * n4 / 1440.0,
+                17.0 * n3 / 480.0 - 37.0 * n4 / 840.0,
+                4397.0 * n4 / 161280.0,
+            ],
+            delta: [
+                2.0 * n - 2.0 * n2 / 3.0 - 2.0 * n3 + 116.0 * n4 / 45.0,
+                7.0 * n2 / 3.0 - 8.0 * n3 / 5.0 - 227.0 * n4 / 45.0,
+                56.0 * n3 / 15.0 - 136.0 * n4 / 35.0,
+                4279.0 * n4 / 630.0,
+            ],
+        }
+    }
+
+    /// longitude/latitude in degrees to easting/northing in meters
+    fn forward(&self, c: Coord) -> Coord {
+        let lat = c.y.to_radians();
+        let dlng = c.x.to_radians() - self.central_meridian;
+        let e = 2.0 * self.n.sqrt() / (1.0 + self.n);
+        let t = (lat.sin().atanh() - e * (e * lat.sin()).atanh()).sinh();
+        let xi = t.atan2(dlng.cos());
+        let eta = (dlng.sin() / (1.0 + t * t).sqrt()).atanh();
+
+        let (mut x, mut y) = (eta, xi);
+        for (j, alpha) in self.alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            x += alpha * (k * xi).cos() * (k * eta).sinh();
+            y += alpha * (k * xi).sin() * (k * eta).cosh();
+        }
+        Coord {
+            x: UTM_FALSE_EASTING + self.k0_a * x,
+            y: self.false_northing + self.k0_a * y,
+        }
+    }
+
+    /// easting/northing in meters to longitude/latitude in degrees
+    fn inverse(&self, c: Coord) -> Coord {
+        let xi = (c.y - self.false_northing) / self.k0_a;
+        let eta = (c.x - UTM_FALSE_EASTING) / self.k0_a;
+
+        let (mut xi_prime, mut eta_prime) = (xi, eta);
+        for (j, beta) in self.beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi_prime -= beta * (k * xi).sin() * (k * eta).cosh();
+            eta_prime -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+        let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
+        let mut lat = chi;
+        for (j, delta) in self.delta.iter().enumerate() {
+            lat += delta * (2.0 * (j + 1) as f64 * chi).sin();
+        }
+        Coord {
+            x: (self.central_meridian + eta_prime.sinh().atan2(xi_prime.cos())).to_degrees(),
+            y: lat.to_degrees(),
+        }
+    }
+}
+
+/// Transform all coordinates of `geom` to WGS84.
+pub fn geometry_to_wgs84(
+    geom: &mut Geometry,
+    transform: &dyn CoordinateTransform,
+) -> Result<(), Error> {
+    transform_geometries([geom], |coords| transform.transform_to_wgs84(coords))
+}
+
+/// Transform all coordinates of the WGS84 geometry `geom` to the coordinate reference system.
+pub fn geometry_from_wgs84(
+    geom: &mut Geometry,
+    transform: &dyn CoordinateTransform,
+) -> Result<(), Error> {
+    transform_geometries([geom], |coords| transform.transform_from_wgs84(coords))
+}
+
+/// Transform all coordinates of `geoms` to WGS84.
+///
+/// The coordinates of all geometries are passed to the transformation at once, which
+/// keeps the overhead of transformations with a high cost per call low - for example
+/// transformations calling into an interpreter which has to be locked for each call.
+pub fn geometries_to_wgs84(
+    geoms: &mut [Option<Geometry>],
+    transform: &dyn CoordinateTransform,
+) -> Result<(), Error> {
+    transform_geometries(geoms.iter_mut().flatten(), |coords| {
+        transform.transform_to_wgs84(coords)
+    })
+}
+
+/// Transform all coordinates of the WGS84 geometries `geoms` to the coordinate reference system.
+///
+/// The coordinates are batched as in [`geometries_to_wgs84`].
+pub fn geometries_from_wgs84(
+    geoms: &mut [Option<Geometry>],
+    transform: &dyn CoordinateTransform,
+) -> Result<(), Error> {
+    transform_geometries(geoms.iter_mut().flatten(), |coords| {
+        transform.transform_from_wgs84(coords)
+    })
+}
+
+/// Transform the coordinates of geometries in a single batch.
+fn transform_geometries<'a, I, F>(geoms: I, transform_fn: F) -> Result<(), Error>
+where
+    I: IntoIterator<Item = &'a mut Geometry>,
+    F: FnOnce(&mut [Coord]) -> Result<(), Error>,
+{
+    let mut geoms = geoms.into_iter().collect::<Vec<_>>();
+    let coords = RefCell::new(Vec::new());
+    for geom in geoms.iter_mut() {
+        geom.map_coords_in_place(|c| {
+            coords.borrow_mut().push(c);
+            c
+        });
+    }
+    let mut coords = coords.into_inner();
+    transform_fn(&mut coords)?;
+
+    // the coordinates are visited in the same order as above
+    let position = Cell::new(0);
+    for geom in geoms.iter_mut() {
+        geom.map_coords_in_place(|_| {
+            let c = coords[position.get()];
+            position.set(position.get() + 1);
+            c
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::from_geo::{geometry_to_cells, ToCellsOptions};
+    use crate::crs::{
+        geometries_from_wgs84, geometries_to_wgs84, geometry_from_wgs84, geometry_to_wgs84,
+        CoordinateTransform, Crs,
+    };
+    use geo::{Distance, Geodesic};
+    use geo_types::{coord, Coord, Geometry, LineString, Point, Rect};
+    use h3o::Resolution;
+
+    fn assert_close(a: Coord, b: Coord, tolerance: f64) {
+        assert!(
+            (a.x - b.x).abs() < tolerance && (a.y - b.y).abs() < tolerance,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn from_epsg() {
+        assert_eq!(Crs::from_epsg(3857).unwrap(), Crs::WebMercator);
+        assert_eq!(
+            "epsg:32632".parse::<Crs>().unwrap(),
+            Crs::Utm {
+                zone: 32,
+                north: true
+            }
+        );
+        assert_eq!(Crs::from_epsg(32733).unwrap().epsg(), 32733);
+        assert!(Crs::from_epsg(2056).is_err());
+        assert!("3857".parse::<Crs>().is_err());
+    }
+
+    #[test]
+    fn web_mercator() {
+        let mut coords = [coord! {x: 13.4050, y: 52.5200}];
+        Crs::WebMercator.transform_from_wgs84(&mut coords).unwrap();
+        assert_close(coords[0], coord! {x: 1492237.77, y: 6894699.80}, 0.01);
+
+        Crs::WebMercator.transform_to_wgs84(&mut coords).unwrap();
+        assert_close(coords[0], coord! {x: 13.4050, y: 52.5200}, 1e-9);
+    }
+
+    #[test]
+    fn utm() {
+        let utm33n = Crs::from_epsg(32633).unwrap();
+
+        // the central meridian of the zone at the equator
+        let mut coords = [coord! {x: 15.0, y: 0.0}];
+        utm33n.transform_from_wgs84(&mut coords).unwrap();
+        assert_close(coords[0], coord! {x: 500_000.0, y: 0.0}, 1e-6);
+
+        // the length of the meridian arc from the equator to 45° is 4984944.378 m
+        let mut coords = [coord! {x: 15.0, y: 45.0}];
+        utm33n.transform_from_wgs84(&mut coords).unwrap();
+        assert_close(
+            coords[0],
+            coord! {x: 500_000.0, y: 0.9996 * 4_984_944.378},
+            0.01,
+        );
+
+        // the distances are scaled by the point scale factor of the projection
+        let (a, b) = (Point::new(13.405, 52.52), Point::new(13.41, 52.525));
+        let mut coords = [a.0, b.0];
+        utm33n.transform_from_wgs84(&mut coords).unwrap();
+        let scale_factor = 0.9996
+            * (1.0 + (-1.5925_f64.to_radians() * 52.5225_f64.to_radians().cos()).powi(2) / 2.0);
+        let projected_distance = (coords[0].x - coords[1].x).hypot(coords[0].y - coords[1].y);
+        assert!((projected_distance / Geodesic::distance(a, b) - scale_factor).abs() < 1e-5);
+
+        utm33n.transform_to_wgs84(&mut coords).unwrap();
+        assert_close(coords[0], a.0, 1e-9);
+        assert_close(coords[1], b.0, 1e-9);
+
+        // southern hemisphere
+        let utm56s = Crs::from_epsg(32756).unwrap();
+        let mut coords = [coord! {x: 151.2093, y: -33.8688}];
+        utm56s.transform_from_wgs84(&mut coords).unwrap();
+        assert!(coords[0].x < 500_000.0 && coords[0].y > 6_000_000.0 && coords[0].y < 10_000_000.0);
+        utm56s.transform_to_wgs84(&mut coords).unwrap();
+        assert_close(coords[0], coord! {x: 151.2093, y: -33.8688}, 1e-9);
+    }
+
+    #[test]
+    fn geometry_roundtrip() {
+        let original = Geometry::from(LineString::from(vec![(10.0, 50.0), (10.5, 50.4)]));
+        let mut geom = original.clone();
+        let crs = Crs::from_epsg(32632).unwrap();
+        geometry_from_wgs84(&mut geom, &crs).unwrap();
+        let Geometry::LineString(projected) = &geom else {
+            unreachable!()
+        };
+        assert!(projected
+            .0
+            .iter()
+            .all(|c| c.x > 400_000.0 && c.y > 5_500_000.0));
+
+        geometry_to_wgs84(&mut geom, &crs).unwrap();
+        let (Geometry::LineString(a), Geometry::LineString(b)) = (&original, &geom) else {
+            unreachable!()
+        };
+        a.0.iter()
+            .zip(b.0.iter())
+            .for_each(|(a, b)| assert_close(*a, *b, 1e-9));
+    }
+
+    #[test]
+    fn projected_geometries_to_cells() {
+        let polygon = Geometry::from(Rect::new((10.0, 50.0), (10.3, 50.2)));
+        let crs = Crs::from_epsg(32632).unwrap();
+        let mut geoms = vec![Some(polygon.clone()), None];
+        geometries_from_wgs84(&mut geoms, &crs).unwrap();
+        assert_ne!(geoms[0].as_ref(), Some(&polygon));
+        assert!(geoms[1].is_none());
+
+        geometries_to_wgs84(&mut geoms, &crs).unwrap();
+        let options = ToCellsOptions::new(Resolution::Eight);
+        let expected = geometry_to_cells(&polygon, &options).unwrap();
+        assert!(!expected.is_empty());
+        assert_eq!(
+            geometry_to_cells(geoms[0].as_ref().unwrap(), &options).unwrap(),
+            expected
+        );
+    }
+}
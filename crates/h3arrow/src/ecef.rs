@@ -0,0 +1,286 @@
+//! Export of cells as Earth-centered, Earth-fixed (ECEF) cartesian coordinates.
+//!
+//! 3D globe viewers typically work with cartesian coordinates instead of longitude/latitude.
+//! The coordinates generated here are located on the surface of the WGS84 ellipsoid and use
+//! meters. Points are stored in `FixedSizeList<Float64, 3>` arrays holding the x, y and z
+//! components.
+use std::sync::Arc;
+
+use ahash::HashMap;
+use arrow::array::{
+    Array, FixedSizeListArray, Float64Array, GenericListArray, OffsetSizeTrait, UInt32Array,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::{DataType, Field};
+use arrow::error::ArrowError;
+use h3o::LatLng;
+
+use crate::array::CellIndexArray;
+use crate::crs::{WGS84_A, WGS84_F};
+use crate::error::Error;
+
+/// The ECEF coordinates of a point on the surface of the WGS84 ellipsoid in meters.
+pub fn latlng_to_ecef(ll: LatLng) -> [f64; 3] {
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let (lat, lng) = (ll.lat_radians(), ll.lng_radians());
+
+    // radius of curvature in the prime vertical
+    let n = WGS84_A / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+    [
+        n * lat.cos() * lng.cos(),
+        n * lat.cos() * lng.sin(),
+        n * (1.0 - e2) * lat.sin(),
+    ]
+}
+
+/// A triangle mesh of a set of cells.
+pub struct EcefMesh {
+    /// The vertices of the mesh.
+    pub vertices: FixedSizeListArray,
+
+    /// Triples of positions in `vertices` forming the triangles. The triangles are ordered
+    /// counter-clockwise when seen from outside of the globe.
+    pub indices: UInt32Array,
+
+    /// The position in the input array of the cell each triangle belongs to.
+    pub triangle_cells: UInt32Array,
+}
+
+pub trait ToEcefOp {
+    /// The centroids of the cells as ECEF coordinates.
+    fn to_ecef_centroids(&self) -> Result<FixedSizeListArray, Error>;
+
+    /// The vertices of the cell boundaries as lists of ECEF coordinates. The rings
+    /// are not closed, the last vertex connects to the first one.
+    fn to_ecef_boundaries<O: OffsetSizeTrait>(&self) -> Result<GenericListArray<O>, Error>;
+
+    /// Triangulate the cells into a mesh. Each cell is split into a fan of triangles around
+    /// its centroid, using the same vertices as `to_ecef_boundaries`.
+    ///
+    /// With `shared_vertices` the vertices of neighboring cells are only emitted once, using
+    /// their coordinates to identify them. Otherwise each cell gets its own copy of its
+    /// vertices. Null cells are skipped.
+    fn to_ecef_mesh(&self, shared_vertices: bool) -> Result<EcefMesh, Error>;
+}
+
+impl ToEcefOp for CellIndexArray {
+    fn to_ecef_centroids(&self) -> Result<FixedSizeListArray, Error> {
+        let mut coords = Vec::with_capacity(self.len() * 3);
+        for cell in self.iter() {
+            coords.extend(cell.map_or([0.0; 3], |cell| latlng_to_ecef(cell.into())));
+        }
+        ecef_array(coords, self.primitive_array().nulls().cloned())
+    }
+
+    fn to_ecef_boundaries<O: OffsetSizeTrait>(&self) -> Result<GenericListArray<O>, Error> {
+        let mut coords = Vec::with_capacity(self.len() * 6 * 3);
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(O::zero());
+        for cell in self.iter() {
+            // null cells result in empty lists
+            if let Some(cell) = cell {
+                for ll in cell.boundary().iter() {
+                    coords.extend(latlng_to_ecef(*ll));
+                }
+            }
+            offsets.push(O::usize_as(coords.len() / 3));
+        }
+
+        let values = ecef_array(coords, None)?;
+        Ok(GenericListArray::try_new(
+            Arc::new(Field::new("item", values.data_type().clone(), false)),
+            OffsetBuffer::new(offsets.into()),
+            Arc::new(values),
+            self.primitive_array().nulls().cloned(),
+        )?)
+    }
+
+    fn to_ecef_mesh(&self, shared_vertices: bool) -> Result<EcefMesh, Error> {
+        let mut coords = Vec::new();
+        let mut indices = Vec::new();
+        let mut triangle_cells = Vec::new();
+        let mut vertex_positions: HashMap<(i64, i64), u32> = HashMap::default();
+
+        let push_vertex = |coords: &mut Vec<f64>, ll: LatLng| {
+            let position = to_u32(coords.len() / 3)?;
+            coords.extend(latlng_to_ecef(ll));
+            Ok::<_, Error>(position)
+        };
+
+        for (cell_position, cell) in self.iter().enumerate() {
+            let Some(cell) = cell else {
+                continue;
+            };
+            let cell_position = to_u32(cell_position)?;
+
+            let center = push_vertex(&mut coords, cell.into())?;
+            let mut ring = Vec::with_capacity(6);
+            for ll in cell.boundary().iter() {
+                let position = if shared_vertices {
+                    let key = vertex_key(*ll);
+                    match vertex_positions.get(&key) {
+                        Some(position) => *position,
+                        None => {
+                            let position = push_vertex(&mut coords, *ll)?;
+                            vertex_positions.insert(key, position);
+                            position
+                        }
+                    }
+                } else {
+                    push_vertex(&mut coords, *ll)?
+                };
+                ring.push(position);
+            }
+
+            for (i, position) in ring.iter().enumerate() {
+                indices.extend([center, *position, ring[(i + 1) % ring.len()]]);
+                triangle_cells.push(cell_position);
+            }
+        }
+
+        Ok(EcefMesh {
+            vertices: ecef_array(coords, None)?,
+            indices: indices.into(),
+            triangle_cells: triangle_cells.into(),
+        })
+    }
+}
+
+/// Identifies the vertices shared by neighboring cells.
+///
+/// The boundaries of neighboring cells are computed separately and may differ in the last bits,
+/// so the coordinates are rounded to 1e-9 radians - about 6 mm.
+fn vertex_key(ll: LatLng) -> (i64, i64) {
+    (
+        (ll.lat_radians() * 1e9).round() as i64,
+        (ll.lng_radians() * 1e9).round() as i64,
+    )
+}
+
+fn to_u32(position: usize) -> Result<u32, Error> {
+    u32::try_from(position).map_err(|_| {
+        ArrowError::InvalidArgumentError("the mesh exceeds the range of u32 indices".to_string())
+            .into()
+    })
+}
+
+/// Build a `FixedSizeList<Float64, 3>` array from the interleaved x, y and z components.
+fn ecef_array(coords: Vec<f64>, nulls: Option<NullBuffer>) -> Result<FixedSizeListArray, Error> {
+    Ok(FixedSizeListArray::try_new(
+        Arc::new(Field::new("item", DataType::Float64, false)),
+        3,
+        Arc::new(Float64Array::from(coords)),
+        nulls,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{latlng_to_ecef, ToEcefOp};
+    use crate::algorithm::radius::{cross, dot};
+    use crate::array::CellIndexArray;
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::Float64Type;
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    fn point(array: &arrow::array::FixedSizeListArray, i: usize) -> [f64; 3] {
+        let values = array.value(i);
+        let values = values.as_primitive::<Float64Type>();
+        [values.value(0), values.value(1), values.value(2)]
+    }
+
+    fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+        [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+    }
+
+    #[test]
+    fn ecef_reference_points() {
+        let equator = latlng_to_ecef(LatLng::new(0.0, 0.0).unwrap());
+        assert!((equator[0] - 6_378_137.0).abs() < 1e-6);
+        assert!(equator[1].abs() < 1e-6);
+        assert!(equator[2].abs() < 1e-6);
+
+        let east = latlng_to_ecef(LatLng::new(0.0, 90.0).unwrap());
+        assert!((east[1] - 6_378_137.0).abs() < 1e-6);
+
+        // semi-minor axis of the WGS84 ellipsoid
+        let pole = latlng_to_ecef(LatLng::new(90.0, 0.0).unwrap());
+        assert!((pole[2] - 6_356_752.314_245).abs() < 1e-3);
+    }
+
+    #[test]
+    fn ecef_centroids_and_boundaries() {
+        let cell = LatLng::new(45.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let cells = CellIndexArray::from(vec![Some(cell), None, Some(cell)]);
+
+        let centroids = cells.to_ecef_centroids().unwrap();
+        assert_eq!(centroids.len(), 3);
+        assert!(centroids.is_null(1));
+        assert_eq!(point(&centroids, 0), latlng_to_ecef(cell.into()));
+
+        let boundaries = cells.to_ecef_boundaries::<i32>().unwrap();
+        assert_eq!(boundaries.len(), 3);
+        assert!(boundaries.is_null(1));
+        assert_eq!(boundaries.value_length(0), 6);
+        assert_eq!(boundaries.value_length(1), 0);
+        assert_eq!(boundaries.value_length(2), 6);
+    }
+
+    #[test]
+    fn ecef_mesh() {
+        let origin = LatLng::new(45.0, 10.0).unwrap().to_cell(Resolution::Five);
+        let cells = CellIndexArray::from(origin.grid_disk::<Vec<_>>(1));
+
+        let mesh = cells.to_ecef_mesh(false).unwrap();
+        assert_eq!(mesh.vertices.len(), 7 * 7);
+        assert_eq!(mesh.indices.len(), 7 * 6 * 3);
+        assert_eq!(mesh.triangle_cells.len(), 7 * 6);
+
+        // 7 centroids, 6 vertices of the center cell and 3 further vertices for each
+        // of the surrounding cells
+        let shared = cells.to_ecef_mesh(true).unwrap();
+        assert_eq!(shared.vertices.len(), 7 + 6 + 6 * 3);
+        assert_eq!(shared.indices.len(), mesh.indices.len());
+        assert_eq!(shared.triangle_cells, mesh.triangle_cells);
+
+        // all triangles face outwards
+        for triangle in shared.indices.values().chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| point(&shared.vertices, triangle[i] as usize));
+            let normal = cross(&sub(b, a), &sub(c, a));
+            assert!(dot(&normal, &a) > 0.0);
+        }
+    }
+
+    #[test]
+    fn ecef_mesh_distortion_vertices() {
+        // class III cells crossing the edges of the icosahedron have distortion vertices
+        let cells = CellIndex::base_cells()
+            .flat_map(|cell| cell.children(Resolution::One))
+            .collect::<Vec<_>>();
+        assert!(cells.iter().any(|cell| cell.boundary().len() > 6));
+        let cells = CellIndexArray::from(cells);
+
+        // the rings of the mesh match the boundaries
+        let mesh = cells.to_ecef_mesh(false).unwrap();
+        let boundaries = cells.to_ecef_boundaries::<i32>().unwrap();
+        assert_eq!(mesh.indices.len(), boundaries.values().len() * 3);
+        let mut triangles = mesh.indices.values().chunks(3);
+        for i in 0..boundaries.len() {
+            let boundary = boundaries.value(i);
+            let boundary = boundary.as_fixed_size_list();
+            for j in 0..boundary.len() {
+                let triangle = triangles.next().unwrap();
+                assert_eq!(
+                    point(&mesh.vertices, triangle[1] as usize),
+                    point(boundary, j)
+                );
+            }
+        }
+
+        // the mesh covers the globe, so euler's formula applies with the centroids
+        // adding one vertex per cell
+        let shared = cells.to_ecef_mesh(true).unwrap();
+        let num_edges = boundaries.values().len() / 2;
+        assert_eq!(shared.vertices.len(), 2 + num_edges);
+    }
+}
@@ -48,9 +48,18 @@ pub enum Error {
     #[error("Invalid WKB encountered")]
     InvalidWKB,
 
+    #[error("Invalid WKT encountered")]
+    InvalidWKT,
+
     #[error("array length mismatch")]
     LengthMismatch,
 
+    #[error("unsupported coordinate reference system: {0}")]
+    UnsupportedCrs(String),
+
+    #[error("coordinate transformation failed: {0}")]
+    CoordinateTransform(String),
+
     #[error(transparent)]
     IO(#[from] std::io::Error),
 }
@@ -3,6 +3,8 @@ pub use h3o;
 
 pub mod algorithm;
 pub mod array;
+pub mod crs;
+pub mod ecef;
 pub mod error;
 pub mod export;
 
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING, Any, Optional, Sequence, Union, cast
 
+import numpy as np
 from arro3.core import Array, ChunkedArray, DataType, RecordBatch
 from arro3.core.types import (
     ArrowArrayExportable,
@@ -12,6 +13,8 @@ from arro3.core.types import (
 from . import h3ronpyrs as _native
 from .h3ronpyrs import (  # noqa: F401
     DEFAULT_CELL_COLUMN_NAME,
+    AntimeridianHandling,
+    AntimeridianMode,
     ContainmentMode,
     op,
     version,
@@ -49,6 +52,9 @@ def _to_arrow_array(
     elif dtype is not None:
         # From arbitrary non-arrow input
         array = Array(cast(Sequence[Any], arr), type=dtype)
+    elif isinstance(arr, np.ndarray):
+        # numpy arrays keep their own data type
+        array = Array.from_numpy(arr)
     else:
         raise ValueError("Unsupported input to _to_arrow_array. Expected array-like or series-like.")
 
@@ -216,6 +222,74 @@ def grid_ring_distances(cellarray, k_min: int, k_max: int, flatten: bool = False
     return op.grid_ring_distances(_to_uint64_array(cellarray), k_min, k_max, flatten=flatten)
 
 
+def grid_disk_within_m(
+    cellarray, distance_m: float, distance_mode: str = "centroid", flatten: bool = False
+) -> RecordBatch:
+    """
+    The cells within `distance_m` meters of each cell.
+
+    In contrast to `grid_disk_distances` the size of the buffer does not vary with the latitude or pentagon
+    distortion. `k` is picked for each cell from the edge length of its resolution and the cells are filtered
+    using the exact great-circle distance.
+
+    Valid values for `distance_mode` are `"centroid"` - the distance between the cell centroids - and
+    `"boundary"` - the shortest distance between the cell boundaries.
+
+    Returns a table with the columns `cell` and `distance_m`. These contain lists unless `flatten` is set to True.
+    """
+    return op.grid_disk_within_m(
+        _to_uint64_array(cellarray), distance_m, distance_mode=distance_mode, flatten=flatten
+    )
+
+
+def cells_dbscan(cellarray, k: int, min_weight: float, weights=None) -> Array:
+    """
+    Density-based clustering (DBSCAN) of cells.
+
+    The neighborhood of a cell is its grid disk of size `k`. A cell forms the core of a cluster when the summed
+    weights of all cells within its neighborhood reach `min_weight`. Cells in the neighborhood of a core cell
+    are assigned to the cluster of that core cell.
+
+    `weights` is an optional array of the same length as `cellarray`. Without it, every cell is weighted
+    with 1.0. Duplicate cells contribute with all of their weights.
+
+    Returns an array with the cluster id for each position of `cellarray`. Noise is set to null.
+    """
+    if weights is not None:
+        weights = _to_arrow_array(weights, DataType.float64())
+    return op.cells_dbscan(_to_uint64_array(cellarray), k, min_weight, weights=weights)
+
+
+def cells_to_edge_flows(cellarray, track_ids=None) -> RecordBatch:
+    """
+    Count the movements between consecutive cells for each directed edge.
+
+    Consecutive rows with the same value in `track_ids` form a cell sequence - for example the output of
+    `h3ronpy.vector.coordinates_to_cell_sequences`. Without `track_ids` all cells form a single sequence.
+    Only movements between neighboring cells are counted, so gaps should be filled beforehand.
+
+    Returns a table with the columns `edge` and `count`. The edges can be converted to linestrings
+    using `h3ronpy.vector.directededges_to_wkb_linestrings`.
+    """
+    if track_ids is not None:
+        track_ids = _to_arrow_array(track_ids)
+    return op.cells_to_edge_flows(_to_uint64_array(cellarray), track_ids=track_ids)
+
+
+def cells_to_od_flows(cellarray, resolution: int, track_ids=None) -> RecordBatch:
+    """
+    Count the cell sequences for each pair of origin and destination cell.
+
+    Consecutive rows with the same value in `track_ids` form a cell sequence. The origin and destination are the
+    first and the last cell of each sequence, changed to the coarser `resolution`.
+
+    Returns a table with the columns `origin`, `destination` and `count`.
+    """
+    if track_ids is not None:
+        track_ids = _to_arrow_array(track_ids)
+    return op.cells_to_od_flows(_to_uint64_array(cellarray), resolution, track_ids=track_ids)
+
+
 def cells_area_m2(cellarray) -> Array:
     return op.cells_area_m2(_to_uint64_array(cellarray))
 
@@ -228,6 +302,96 @@ def cells_area_rads2(cellarray) -> Array:
     return op.cells_area_rads2(_to_uint64_array(cellarray))
 
 
+def cells_distance_m(cellarray, othercellarray) -> Array:
+    """
+    Great-circle distance in meters between the centroids of the cells at the same positions of both arrays.
+    """
+    return op.cells_distance_m(_to_uint64_array(cellarray), _to_uint64_array(othercellarray))
+
+
+def cells_distance_to_coordinates_m(cellarray, latarray, lngarray, radians: bool = False) -> Array:
+    """
+    Great-circle distance in meters between the centroid of each cell and the coordinate at the same position.
+
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    """
+    return op.cells_distance_to_coordinates_m(
+        _to_uint64_array(cellarray),
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        radians=radians,
+    )
+
+
+def cells_distance_to_point_m(cellarray, lat: float, lng: float, radians: bool = False) -> Array:
+    """
+    Great-circle distance in meters between the centroid of each cell and a single point.
+
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    """
+    return op.cells_distance_to_point_m(_to_uint64_array(cellarray), lat, lng, radians=radians)
+
+
+def cells_distance_km(cellarray, othercellarray) -> Array:
+    """
+    Great-circle distance in kilometers between the centroids of the cells at the same positions of both arrays.
+    """
+    return op.cells_distance_km(_to_uint64_array(cellarray), _to_uint64_array(othercellarray))
+
+
+def cells_distance_to_coordinates_km(cellarray, latarray, lngarray, radians: bool = False) -> Array:
+    """
+    Great-circle distance in kilometers between the centroid of each cell and the coordinate at the same position.
+
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    """
+    return op.cells_distance_to_coordinates_km(
+        _to_uint64_array(cellarray),
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        radians=radians,
+    )
+
+
+def cells_distance_to_point_km(cellarray, lat: float, lng: float, radians: bool = False) -> Array:
+    """
+    Great-circle distance in kilometers between the centroid of each cell and a single point.
+
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    """
+    return op.cells_distance_to_point_km(_to_uint64_array(cellarray), lat, lng, radians=radians)
+
+
+def cells_distance_rads(cellarray, othercellarray) -> Array:
+    """
+    Great-circle distance in radians between the centroids of the cells at the same positions of both arrays.
+    """
+    return op.cells_distance_rads(_to_uint64_array(cellarray), _to_uint64_array(othercellarray))
+
+
+def cells_distance_to_coordinates_rads(cellarray, latarray, lngarray, radians: bool = False) -> Array:
+    """
+    Great-circle distance in radians between the centroid of each cell and the coordinate at the same position.
+
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    """
+    return op.cells_distance_to_coordinates_rads(
+        _to_uint64_array(cellarray),
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        radians=radians,
+    )
+
+
+def cells_distance_to_point_rads(cellarray, lat: float, lng: float, radians: bool = False) -> Array:
+    """
+    Great-circle distance in radians between the centroid of each cell and a single point.
+
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    """
+    return op.cells_distance_to_point_rads(_to_uint64_array(cellarray), lat, lng, radians=radians)
+
+
 def cells_to_string(cellarray) -> Array:
     return op.cells_to_string(_to_uint64_array(cellarray))
 
@@ -286,6 +450,8 @@ def localij_to_cells(anchor, i, j, set_failing_to_invalid: bool = False) -> Arra
 __all__ = [
     "H3_CRS",
     "DEFAULT_CELL_COLUMN_NAME",
+    AntimeridianHandling.__name__,
+    AntimeridianMode.__name__,
     ContainmentMode.__name__,
     version.__name__,
     change_resolution.__name__,
@@ -303,10 +469,23 @@ __all__ = [
     grid_disk.__name__,
     grid_disk_distances.__name__,
     grid_ring_distances.__name__,
+    grid_disk_within_m.__name__,
     grid_disk_aggregate_k.__name__,
+    cells_dbscan.__name__,
+    cells_to_edge_flows.__name__,
+    cells_to_od_flows.__name__,
     cells_area_m2.__name__,
     cells_area_km2.__name__,
     cells_area_rads2.__name__,
+    cells_distance_m.__name__,
+    cells_distance_to_coordinates_m.__name__,
+    cells_distance_to_point_m.__name__,
+    cells_distance_km.__name__,
+    cells_distance_to_coordinates_km.__name__,
+    cells_distance_to_point_km.__name__,
+    cells_distance_rads.__name__,
+    cells_distance_to_coordinates_rads.__name__,
+    cells_distance_to_point_rads.__name__,
     cells_to_string.__name__,
     vertexes_to_string.__name__,
     directededges_to_string.__name__,
@@ -21,13 +21,15 @@ def raster_to_dataframe(
     axis_order: str = "yx",
     compact: bool = True,
     geo: bool = False,
+    crs=None,
 ) -> typing.Union[gpd.GeoDataFrame, pd.DataFrame]:
     """
     Convert a raster/array to a pandas `DataFrame` containing H3 indexes
 
-    This function is parallelized and uses the available CPUs by distributing tiles to a thread pool.
+    This function is parallelized and uses the available CPUs by distributing tiles to a thread pool. This does
+    not apply to rasters using the ``crs`` parameter, these are converted on a single thread.
 
-    The input geometry must be in WGS84.
+    The input geometry must be in WGS84 unless the ``crs`` is set.
 
     :param in_raster: input 2-d array
     :param transform:  the affine transformation
@@ -37,6 +39,7 @@ def raster_to_dataframe(
     :param compact: Return compacted h3 indexes (see H3 docs). This results in mixed H3 resolutions, but also can
             reduce the amount of required memory.
     :param geo: Return a geopandas `GeoDataFrame` with geometries. increases the memory usage.
+    :param crs: The coordinate reference system of the transform when it is not WGS84.
     :return: pandas `DataFrame` or `GeoDataFrame`
     """
 
@@ -47,6 +50,7 @@ def raster_to_dataframe(
         nodata_value=nodata_value,
         axis_order=axis_order,
         compact=compact,
+        crs=crs,
     ).to_pandas()
 
     if geo:
@@ -7,7 +7,7 @@ import pyarrow as pa
 import pyarrow.compute as pc
 
 import h3ronpy.vector as _hv
-from h3ronpy import DEFAULT_CELL_COLUMN_NAME, H3_CRS, ContainmentMode
+from h3ronpy import DEFAULT_CELL_COLUMN_NAME, H3_CRS, AntimeridianHandling, ContainmentMode
 
 
 def _geoseries_from_wkb(func, doc: Optional[str] = None, name: Optional[str] = None):
@@ -77,6 +77,12 @@ def geodataframe_to_cells(
     containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
     compact: bool = False,
     cell_column_name: str = DEFAULT_CELL_COLUMN_NAME,
+    antimeridian_handling: AntimeridianHandling = AntimeridianHandling.Heuristic,
+    min_resolution: Optional[int] = None,
+    max_cells: Optional[int] = None,
+    line_buffer_k: Optional[int] = None,
+    line_buffer_m: Optional[float] = None,
+    crs=None,
 ) -> pd.DataFrame:
     """
     Convert a `GeoDataFrame` to H3 cells while exploding all other columns according to the number of cells derived
@@ -94,6 +100,23 @@ def geodataframe_to_cells(
     :param compact: Compact the returned cells by replacing cells with their parent cells when all children
             of that cell are part of the set.
     :param cell_column_name:
+    :param antimeridian_handling: Handling of polygons crossing the antimeridian or enclosing a pole.
+            See the AntimeridianHandling class.
+    :param min_resolution: Fill polygons adaptively using cells between ``min_resolution`` and ``resolution``.
+            See ``h3ronpy.vector.wkb_to_cells``.
+    :param max_cells: Return a covering of at most ``max_cells`` cells with resolutions between ``min_resolution``
+            (or 0) and ``resolution`` which minimizes the area outside of the geometry. This is useful for prefilters
+            and query ranges. Coarser cells are used when the covering at ``min_resolution`` exceeds ``max_cells``.
+            The containment mode and ``compact`` are ignored.
+    :param line_buffer_k: Widen the cells of lines to all cells within ``line_buffer_k`` grid steps of the cells
+            the lines pass through.
+    :param line_buffer_m: Widen the cells of lines to all cells with their centroid within ``line_buffer_m`` meters
+            of the line. The distance is measured along great circles, so the width of the corridor does not depend
+            on the latitude. Can not be combined with ``line_buffer_k``.
+    :param crs: The coordinate reference system of the input geometries: an EPSG code like ``3857`` or
+            ``"EPSG:32632"`` for the built-in Web Mercator and UTM transformations, or an object providing
+            ``to_wgs84(x, y)`` and ``from_wgs84(x, y)`` methods for numpy arrays of coordinates. Defaults to
+            WGS84 longitude/latitude.
     :return:
     """
     cells = _hv.wkb_to_cells(
@@ -102,6 +125,12 @@ def geodataframe_to_cells(
         containment_mode=containment_mode,
         compact=compact,
         flatten=False,
+        antimeridian_handling=antimeridian_handling,
+        min_resolution=min_resolution,
+        max_cells=max_cells,
+        line_buffer_k=line_buffer_k,
+        line_buffer_m=line_buffer_m,
+        crs=crs,
     )
     table = pa.Table.from_pandas(pd.DataFrame(gdf.drop(columns=gdf.geometry.name))).append_column(
         cell_column_name, cells
@@ -61,6 +61,19 @@ class H3Expr:
 
         return self._expr.map_batches(wrapped_func, return_dtype=return_dtype)
 
+    def __exprs_map_series(
+        self,
+        func: typing.Callable[..., ArrowArrayExportable],
+        others: typing.Sequence[str | pl.Expr],
+        return_dtype: pl.PolarsDataType | None = None,
+    ) -> pl.Expr:
+        def wrapped_func(series: typing.Sequence[pl.Series]) -> pl.Series:
+            arrays = [ChunkedArray.from_arrow(s).combine_chunks() for s in series]
+            return pl.Series(func(*arrays))
+
+        others = [pl.col(other) if isinstance(other, str) else other for other in others]
+        return pl.map_batches([self._expr, *others], wrapped_func, return_dtype=return_dtype)
+
     def cells_resolution(self) -> pl.Expr:
         return self.__expr_map_series(h3ronpy.cells_resolution, return_dtype=pl.UInt8).alias("resolution")
 
@@ -111,6 +124,72 @@ class H3Expr:
     def cells_area_rads2(self) -> pl.Expr:
         return self.__expr_map_series(h3ronpy.cells_area_rads2, return_dtype=pl.Float64).alias("area_rads2")
 
+    def cells_distance_m(self, other: str | pl.Expr) -> pl.Expr:
+        return self.__exprs_map_series(h3ronpy.cells_distance_m, [other], return_dtype=pl.Float64).alias(
+            "distance_m"
+        )
+
+    def cells_distance_to_coordinates_m(
+        self, lat: str | pl.Expr, lng: str | pl.Expr, radians: bool = False
+    ) -> pl.Expr:
+        return self.__exprs_map_series(
+            lambda cells, lat, lng: h3ronpy.cells_distance_to_coordinates_m(
+                cells, lat, lng, radians=radians
+            ),
+            [lat, lng],
+            return_dtype=pl.Float64,
+        ).alias("distance_m")
+
+    def cells_distance_to_point_m(self, lat: float, lng: float, radians: bool = False) -> pl.Expr:
+        return self.__expr_map_series(
+            lambda s: h3ronpy.cells_distance_to_point_m(s, lat, lng, radians=radians),
+            return_dtype=pl.Float64,
+        ).alias("distance_m")
+
+    def cells_distance_km(self, other: str | pl.Expr) -> pl.Expr:
+        return self.__exprs_map_series(h3ronpy.cells_distance_km, [other], return_dtype=pl.Float64).alias(
+            "distance_km"
+        )
+
+    def cells_distance_to_coordinates_km(
+        self, lat: str | pl.Expr, lng: str | pl.Expr, radians: bool = False
+    ) -> pl.Expr:
+        return self.__exprs_map_series(
+            lambda cells, lat, lng: h3ronpy.cells_distance_to_coordinates_km(
+                cells, lat, lng, radians=radians
+            ),
+            [lat, lng],
+            return_dtype=pl.Float64,
+        ).alias("distance_km")
+
+    def cells_distance_to_point_km(self, lat: float, lng: float, radians: bool = False) -> pl.Expr:
+        return self.__expr_map_series(
+            lambda s: h3ronpy.cells_distance_to_point_km(s, lat, lng, radians=radians),
+            return_dtype=pl.Float64,
+        ).alias("distance_km")
+
+    def cells_distance_rads(self, other: str | pl.Expr) -> pl.Expr:
+        return self.__exprs_map_series(h3ronpy.cells_distance_rads, [other], return_dtype=pl.Float64).alias(
+            "distance_rads"
+        )
+
+    def cells_distance_to_coordinates_rads(
+        self, lat: str | pl.Expr, lng: str | pl.Expr, radians: bool = False
+    ) -> pl.Expr:
+        return self.__exprs_map_series(
+            lambda cells, lat, lng: h3ronpy.cells_distance_to_coordinates_rads(
+                cells, lat, lng, radians=radians
+            ),
+            [lat, lng],
+            return_dtype=pl.Float64,
+        ).alias("distance_rads")
+
+    def cells_distance_to_point_rads(self, lat: float, lng: float, radians: bool = False) -> pl.Expr:
+        return self.__expr_map_series(
+            lambda s: h3ronpy.cells_distance_to_point_rads(s, lat, lng, radians=radians),
+            return_dtype=pl.Float64,
+        ).alias("distance_rads")
+
     def cells_valid(self) -> pl.Expr:
         return self.__expr_map_series(h3ronpy.cells_valid, return_dtype=pl.Boolean).alias("cells_valid")
 
@@ -178,6 +257,33 @@ class H3SeriesShortcuts:
     def cells_area_rads2(self) -> pl.Series:
         return _wrap(h3ronpy.cells_area_rads2)(self._s)
 
+    def cells_distance_m(self, other: pl.Series) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_m)(self._s, other)
+
+    def cells_distance_to_coordinates_m(self, lat: pl.Series, lng: pl.Series, radians: bool = False) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_to_coordinates_m)(self._s, lat, lng, radians=radians)
+
+    def cells_distance_to_point_m(self, lat: float, lng: float, radians: bool = False) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_to_point_m)(self._s, lat, lng, radians=radians)
+
+    def cells_distance_km(self, other: pl.Series) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_km)(self._s, other)
+
+    def cells_distance_to_coordinates_km(self, lat: pl.Series, lng: pl.Series, radians: bool = False) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_to_coordinates_km)(self._s, lat, lng, radians=radians)
+
+    def cells_distance_to_point_km(self, lat: float, lng: float, radians: bool = False) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_to_point_km)(self._s, lat, lng, radians=radians)
+
+    def cells_distance_rads(self, other: pl.Series) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_rads)(self._s, other)
+
+    def cells_distance_to_coordinates_rads(self, lat: pl.Series, lng: pl.Series, radians: bool = False) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_to_coordinates_rads)(self._s, lat, lng, radians=radians)
+
+    def cells_distance_to_point_rads(self, lat: float, lng: float, radians: bool = False) -> pl.Series:
+        return _wrap(h3ronpy.cells_distance_to_point_rads)(self._s, lat, lng, radians=radians)
+
     def cells_valid(self) -> pl.Series:
         return _wrap(h3ronpy.cells_valid)(self._s)
 
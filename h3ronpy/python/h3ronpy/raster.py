@@ -8,7 +8,10 @@ a `GDAL-like array <https://gdal.org/tutorials/geotransforms_tut.html>`_ of six
 .. note::
 
     As H3 itself used WGS84 (EPSG:4326) Lat/Lon coordinates, the coordinate transformation matrix used in this module
-    must be based on WGS84 as well. Raster data using other coordinate systems need to be reprojected accordingly.
+    is expected to be based on WGS84 as well. For raster data using other coordinate systems the ``crs`` parameter
+    can be set to an EPSG code of Web Mercator (3857) or of an UTM zone (326xx, 327xx), or to an object providing
+    ``to_wgs84(x, y)`` and ``from_wgs84(x, y)`` methods for numpy arrays of coordinates - for example wrapping
+    ``pyproj`` transformers. The values are then sampled at the centroids of the cells.
 
 
 While H3 cells are hexagons and pentagons, this raster conversion process only takes the raster value under the centroid
@@ -35,7 +38,7 @@ import pyarrow as pa
 
 from h3ronpy import DEFAULT_CELL_COLUMN_NAME, _to_arrow_array, _to_uint64_array
 from h3ronpy.h3ronpyrs import raster
-from h3ronpy.vector import cells_bounds, cells_to_wkb_polygons
+from h3ronpy.vector import cells_bounds, cells_to_wkb_multipolygons_by_key
 
 try:
     # affine library is used by rasterio
@@ -60,7 +63,7 @@ def _get_transform(t):
     raise ValueError("unsupported object for transform")
 
 
-def nearest_h3_resolution(shape, transform, axis_order="yx", search_mode="min_diff") -> int:
+def nearest_h3_resolution(shape, transform, axis_order="yx", search_mode="min_diff", crs=None) -> int:
     """
     Find the H3 resolution closest to the size of a pixel in an array
     of the given shape with the given transform
@@ -69,9 +72,11 @@ def nearest_h3_resolution(shape, transform, axis_order="yx", search_mode="min_di
     :param transform: the affine transformation
     :param axis_order: axis order of the 2d array. Either "xy" or "yx"
     :param search_mode: resolution search mode (see documentation of this module)
+    :param crs: The coordinate reference system of the transform when it is not WGS84 (see documentation of this
+            module)
     :return:
     """
-    return raster.nearest_h3_resolution(shape, _get_transform(transform), axis_order, search_mode)
+    return raster.nearest_h3_resolution(shape, _get_transform(transform), axis_order, search_mode, crs=crs)
 
 
 def raster_to_dataframe(
@@ -81,13 +86,15 @@ def raster_to_dataframe(
     nodata_value=None,
     axis_order: str = "yx",
     compact: bool = True,
+    crs=None,
 ) -> pa.Table:
     """
     Convert a raster/array to a pandas `DataFrame` containing H3 cell indexes
 
-    This function is parallelized and uses the available CPUs by distributing tiles to a thread pool.
+    This function is parallelized and uses the available CPUs by distributing tiles to a thread pool. This does
+    not apply to rasters using the ``crs`` parameter, these are converted on a single thread.
 
-    The input geometry must be in WGS84.
+    The input geometry must be in WGS84 unless the ``crs`` is set.
 
     :param in_raster: Input 2D array
     :param transform:  The affine transformation
@@ -96,6 +103,8 @@ def raster_to_dataframe(
     :param h3_resolution: Target h3 resolution
     :param compact: Return compacted h3 indexes (see H3 docs). This results in mixed H3 resolutions, but also can
             reduce the amount of required memory.
+    :param crs: The coordinate reference system of the transform when it is not WGS84 (see documentation of this
+            module)
     :return: Tuple of arrow arrays
     """
 
@@ -132,6 +141,7 @@ def raster_to_dataframe(
             axis_order,
             compact,
             nodata_value,
+            crs,
         ),
         names=["value", DEFAULT_CELL_COLUMN_NAME],
     )
@@ -174,38 +184,31 @@ def rasterize_cells(
 
     transform = from_bounds(*bounds, *size)
 
-    # reduce the number of features to loop over by grouping by value
-    # https://arrow.apache.org/docs/python/generated/pyarrow.TableGroupBy.html
-    grouped = (
-        pa.Table.from_arrays([cells, values], names=["cells", "values"])
-        .group_by("values")
-        .aggregate(
-            [
-                ("cells", "hash_distinct"),
-            ]
-        )
-    )
+    # reduce the number of features to loop over by dissolving the cells of each value.
+    # linking cells should speed up rendering in case of large homogenous areas
+    grouped = cells_to_wkb_multipolygons_by_key(cells, values)
 
     # drop any unused references to free some memory
     del cells
     del values
 
-    values_array = grouped["values"].to_numpy()
-    rasterized = np.full(size, nodata_value, dtype=values_array.dtype)
-
-    for cells, value in zip(grouped["cells_distinct"], grouped["values"]):
-        value = value.as_py()
+    keys = pa.array(grouped["key"])
+    geometries = pa.array(grouped["geometry"])
+    rasterized = np.full(size, nodata_value, dtype=keys.type.to_pandas_dtype())
 
-        # linking cells should speed up rendering in case of large homogenous areas
-        polygons = pa.array(cells_to_wkb_polygons(pa.array(cells), link_cells=True))
-        polygons = [shapely.from_wkb(polygon.as_py()) for polygon in polygons.filter(polygons.is_valid())]
+    for value, geometry in zip(keys, geometries):
+        if not (value.is_valid and geometry.is_valid):
+            continue
+        multipolygon = shapely.from_wkb(geometry.as_py())
+        if multipolygon.is_empty:
+            continue
 
         # draw
         rasterize(
-            polygons,
+            [multipolygon],
             out_shape=size,
             out=rasterized,
-            default_value=value,
+            default_value=value.as_py(),
             transform=transform,
             all_touched=False,
         )
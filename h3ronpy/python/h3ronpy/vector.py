@@ -1,8 +1,9 @@
-from typing import Optional, Tuple
+from datetime import timedelta
+from typing import Optional, Tuple, Union
 
 from arro3.core import Array, DataType, RecordBatch
 
-from h3ronpy import ContainmentMode
+from h3ronpy import AntimeridianHandling, AntimeridianMode, ContainmentMode
 
 from . import _to_arrow_array, _to_uint64_array
 from .h3ronpyrs import vector
@@ -15,6 +16,47 @@ def cells_to_coordinates(arr, radians: bool = False) -> RecordBatch:
     return vector.cells_to_coordinates(_to_uint64_array(arr), radians=radians)
 
 
+def cells_to_ecef_centroids(arr) -> Array:
+    """
+    Convert cells to the Earth-centered, Earth-fixed (ECEF) cartesian coordinates of their centroids.
+
+    The coordinates are located on the surface of the WGS84 ellipsoid and use meters. The returned
+    ``FixedSizeList<Float64, 3>`` array matches the order of the input array.
+
+    :param arr: The cell array
+    """
+    return vector.cells_to_ecef_centroids(_to_uint64_array(arr))
+
+
+def cells_to_ecef_boundaries(arr) -> Array:
+    """
+    Convert cells to lists of the ECEF coordinates of their boundary vertices.
+
+    The rings are not closed, the last vertex connects to the first one. The returned array matches the
+    order of the input array.
+
+    :param arr: The cell array
+    """
+    return vector.cells_to_ecef_boundaries(_to_uint64_array(arr))
+
+
+def cells_to_ecef_mesh(arr, shared_vertices: bool = True) -> Tuple[Array, Array, Array]:
+    """
+    Triangulate cells into a mesh using ECEF coordinates, for example to render them in a 3D globe viewer.
+
+    Each cell is split into a fan of triangles around its centroid. Returns a tuple of three arrays:
+
+    * the vertices as ``FixedSizeList<Float64, 3>`` array.
+    * the triangles as triples of positions in the vertices array, ordered counter-clockwise when seen from
+      outside of the globe.
+    * the position of the cell in the input array for each triangle.
+
+    :param arr: The cell array
+    :param shared_vertices: Emit the vertices shared by neighboring cells only once.
+    """
+    return vector.cells_to_ecef_mesh(_to_uint64_array(arr), shared_vertices=shared_vertices)
+
+
 def coordinates_to_cells(latarray, lngarray, resarray, radians: bool = False) -> Array:
     """
     Convert coordinates arrays to cells.
@@ -37,9 +79,185 @@ def coordinates_to_cells(latarray, lngarray, resarray, radians: bool = False) ->
     )
 
 
+def coordinates_to_cells_within_radius(
+    latarray,
+    lngarray,
+    radiusarray,
+    resolution: int,
+    containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
+    radians: bool = False,
+) -> Array:
+    """
+    Convert circles given by their center coordinates and their radius in meters to cells.
+
+    The cells are found by searching the grid around each center and filtering the cells using exact
+    great-circle distances, so the circles keep their geodesic shape at all latitudes. This is more
+    accurate than building circle polygons and converting these using `geometry_to_cells`.
+
+    A list array is returned, with the cells of each circle located at the same position as the circle
+    in the input arrays. Circles with a null center or radius result in null lists.
+
+    :param latarray: array of lat values of the centers
+    :param lngarray: array of lng values of the centers
+    :param radiusarray: array of radius values in meters
+    :param resolution: H3 resolution
+    :param containment_mode: Containment mode used to decide if a cell is contained in a circle or not.
+            See the ContainmentMode class.
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    :return: list array of cells
+    """
+    return vector.coordinates_to_cells_within_radius(
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        _to_arrow_array(radiusarray, DataType.float64()),
+        resolution,
+        containment_mode=containment_mode,
+        radians=radians,
+    )
+
+
+def coordinates_to_hexbins(latarray, lngarray, resarray, values=None, radians: bool = False) -> RecordBatch:
+    """
+    Bin coordinates into cells and aggregate values for each cell.
+
+    This is equivalent to converting the coordinates to cells using `coordinates_to_cells` and
+    grouping by the cells afterward - without building the intermediate cell array. The conversion is
+    parallelized using the available CPUs.
+
+    The returned table contains a row for each distinct cell with the columns `cell` and `count` - the number
+    of coordinates located in that cell. For each column of ``values`` the columns ``<name>_sum``,
+    ``<name>_mean``, ``<name>_min`` and ``<name>_max`` are added. Null values are ignored.
+
+    :param latarray: array of lat values
+    :param lngarray: array of lng values
+    :param resarray: Either an array of resolutions or a single resolution as an integer to apply to all coordinates.
+    :param values: Optional table/record batch or dict of numeric value columns. The columns must be of the same
+            length as the coordinate arrays.
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    :return: table with one row per cell
+    """
+    return vector.coordinates_to_hexbins(
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        _to_resolution(resarray),
+        values=_to_values_batch(values),
+        radians=radians,
+    )
+
+
+def coordinates_to_spacetime_bins(
+    latarray,
+    lngarray,
+    timestamparray,
+    resarray,
+    bucket: Union[str, timedelta],
+    values=None,
+    radians: bool = False,
+) -> RecordBatch:
+    """
+    Bin coordinates and their timestamps into cells and time buckets and aggregate values for each bin.
+
+    Time buckets are either of a fixed duration - given as a ``datetime.timedelta`` - aligned to the unix epoch, or
+    calendar units. Supported calendar units are ``"day"``, ``"week"`` (starting on monday), ``"month"``, ``"quarter"``
+    and ``"year"``. Calendar buckets are computed in UTC.
+
+    The returned table contains a row for each distinct bin, sorted by ``cell`` and ``bucket_start``. Next to
+    these columns it contains the ``key`` column - a 16-byte binary combining cell and bucket start which sorts in
+    the same order and can be used for partitioning - and the ``count`` of coordinates located in the bin. For each
+    column of ``values`` the columns ``<name>_sum``, ``<name>_mean``, ``<name>_min`` and ``<name>_max`` are added.
+
+    :param latarray: array of lat values
+    :param lngarray: array of lng values
+    :param timestamparray: array of arrow timestamps. ``bucket_start`` uses the same unit and timezone.
+    :param resarray: Either an array of resolutions or a single resolution as an integer to apply to all coordinates.
+    :param bucket: The time bucket as a ``datetime.timedelta`` or the name of a calendar unit.
+    :param values: Optional table/record batch or dict of numeric value columns. The columns must be of the same
+            length as the coordinate arrays.
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    :return: table with one row per bin
+    """
+    if isinstance(bucket, timedelta):
+        bucket = bucket // timedelta(microseconds=1)
+        if bucket <= 0:
+            raise ValueError("the duration of time buckets must be positive")
+    return vector.coordinates_to_spacetime_bins(
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        _to_arrow_array(timestamparray),
+        _to_resolution(resarray),
+        bucket,
+        values=_to_values_batch(values),
+        radians=radians,
+    )
+
+
+def coordinates_to_cell_sequences(
+    latarray,
+    lngarray,
+    timestamparray,
+    resolution: int,
+    track_ids=None,
+    fill_gaps: bool = True,
+    radians: bool = False,
+) -> RecordBatch:
+    """
+    Convert ordered tracks of coordinates and timestamps to run-length-encoded cell sequences.
+
+    Consecutive rows with the same value in ``track_ids`` form a track - the points of each track must be ordered by
+    time. Without ``track_ids`` all points form a single track. Consecutive points located in the same cell are
+    collapsed into a single row with the columns ``cell``, ``entry_time``, ``exit_time`` and ``point_count``.
+    When ``track_ids`` are given, the ``track_id`` of each row is added as the first column.
+
+    With ``fill_gaps`` enabled, the grid path between consecutive non-neighboring cells is inserted. These cells have a
+    ``point_count`` of 0 and linearly interpolated entry and exit times.
+
+    :param latarray: array of lat values
+    :param lngarray: array of lng values
+    :param timestamparray: array of arrow timestamps
+    :param resolution: H3 resolution
+    :param track_ids: Optional array of track ids.
+    :param fill_gaps: Fill the gaps between non-neighboring cells with grid paths.
+    :param radians: Set to True to pass `lat` and `lng` in radians
+    :return: table with one row per cell visit
+    """
+    return vector.coordinates_to_cell_sequences(
+        _to_arrow_array(latarray, DataType.float64()),
+        _to_arrow_array(lngarray, DataType.float64()),
+        _to_arrow_array(timestamparray),
+        resolution,
+        track_ids=None if track_ids is None else _to_arrow_array(track_ids),
+        fill_gaps=fill_gaps,
+        radians=radians,
+    )
+
+
+def _to_resolution(resarray):
+    if type(resarray) in (int, float):
+        return int(resarray)
+    return _to_arrow_array(resarray, DataType.uint8())
+
+
+def _to_values_batch(values) -> Optional[RecordBatch]:
+    if isinstance(values, dict):
+        return RecordBatch.from_pydict({name: _to_arrow_array(arr, DataType.float64()) for name, arr in values.items()})
+    elif values is not None and not isinstance(values, RecordBatch):
+        return RecordBatch.from_arrow(values)
+    return values
+
+
+def _to_geometry_array(arr) -> Array:
+    if hasattr(arr, "__arrow_c_array__") or hasattr(arr, "__arrow_c_stream__") or hasattr(arr, "to_arrow"):
+        # keep the type of arrow input to be able to detect WKT and geoarrow arrays
+        return _to_arrow_array(arr)
+    # everything else is expected to be a sequence of WKB geometries
+    return _to_arrow_array(arr, DataType.binary())
+
+
 def cells_bounds(arr) -> Optional[Tuple]:
     """
     Bounds of the complete array as a tuple `(minx, miny, maxx, maxy)`.
+
+    For cells crossing or spanning the antimeridian, `maxx` exceeds 180°.
     """
     return vector.cells_bounds(_to_uint64_array(arr))
 
@@ -48,11 +266,22 @@ def cells_bounds_arrays(arr) -> RecordBatch:
     """
     Build a table/dataframe with the columns `minx`, `miny`, `maxx` and `maxy` containing the bounds of the individual
     cells from the input array.
+
+    As with `cells_bounds`, `maxx` exceeds 180° for cells crossing the antimeridian.
     """
     return vector.cells_bounds_arrays(_to_uint64_array(arr))
 
 
-def cells_to_wkb_polygons(arr, radians: bool = False, link_cells: bool = False) -> Array:
+def cells_to_wkb_polygons(
+    arr,
+    radians: bool = False,
+    link_cells: bool = False,
+    antimeridian_mode: AntimeridianMode = AntimeridianMode.Keep,
+    crs=None,
+    max_segment_length_m: Optional[float] = None,
+    max_segment_angle_deg: Optional[float] = None,
+    descendant_resolution: Optional[int] = None,
+) -> Array:
     """
     Convert cells to polygons.
 
@@ -62,8 +291,85 @@ def cells_to_wkb_polygons(arr, radians: bool = False, link_cells: bool = False)
     :param: arr: The cell array
     :param radians: Generate geometries using radians instead of degrees
     :param link_cells: Combine neighboring cells into a single polygon geometry. All cell indexes must have the same resolution.
+    :param antimeridian_mode: Handling of polygons crossing the antimeridian. ``AntimeridianMode.Split`` generates
+            multipolygons.
+    :param crs: Generate geometries in this coordinate reference system instead of WGS84. Accepts the same values
+            as the ``crs`` parameter of ``wkb_to_cells``. Can not be combined with ``radians``.
+    :param max_segment_length_m: Insert points along the great circles between the vertices, so no segment is
+            longer than this length in meters. Keeps neighboring cells tiling after projecting the geometries.
+    :param max_segment_angle_deg: Like ``max_segment_length_m``, but limiting the angle spanned by the segments
+            to this number of degrees. Can not be combined with ``max_segment_length_m``.
+    :param descendant_resolution: Render each cell as the outline of the union of its descendants at this resolution
+            instead of its own boundary. Compacted cells then align with the uncompacted cells. Cells at this
+            resolution or finer are unchanged. Can not be combined with ``link_cells``.
+    """
+    return vector.cells_to_wkb_polygons(
+        _to_uint64_array(arr),
+        radians=radians,
+        link_cells=link_cells,
+        antimeridian_mode=antimeridian_mode,
+        crs=crs,
+        max_segment_length_m=max_segment_length_m,
+        max_segment_angle_deg=max_segment_angle_deg,
+        descendant_resolution=descendant_resolution,
+    )
+
+
+def celllists_to_wkb_multipolygons(
+    arr,
+    radians: bool = False,
+    antimeridian_mode: AntimeridianMode = AntimeridianMode.Keep,
+    max_segment_length_m: Optional[float] = None,
+    max_segment_angle_deg: Optional[float] = None,
+) -> Array:
+    """
+    Dissolve the cells of each list of a list array into a multipolygon.
+
+    The returned geometries in the output array will match the order of the input array. Together with
+    ``wkb_to_cells(flatten=False)`` this allows converting geometries to cells and back while preserving the
+    rows of the input.
+
+    :param arr: The list array of cells, like it is returned by ``wkb_to_cells``.
+    :param radians: Generate geometries using radians instead of degrees
+    :param antimeridian_mode: Handling of polygons crossing the antimeridian.
+    :param max_segment_length_m: Insert points along the great circles between the vertices, so no segment is
+            longer than this length in meters.
+    :param max_segment_angle_deg: Like ``max_segment_length_m``, but limiting the angle spanned by the segments
+            to this number of degrees. Can not be combined with ``max_segment_length_m``.
     """
-    return vector.cells_to_wkb_polygons(_to_uint64_array(arr), radians=radians, link_cells=link_cells)
+    return vector.celllists_to_wkb_multipolygons(
+        _to_arrow_array(arr, DataType.large_list(DataType.uint64())),
+        radians=radians,
+        antimeridian_mode=antimeridian_mode,
+        max_segment_length_m=max_segment_length_m,
+        max_segment_angle_deg=max_segment_angle_deg,
+    )
+
+
+def cells_to_wkb_multipolygons_by_key(
+    arr,
+    keys,
+    radians: bool = False,
+    antimeridian_mode: AntimeridianMode = AntimeridianMode.Keep,
+) -> RecordBatch:
+    """
+    Group the cells by the value at the same position in ``keys`` and dissolve the cells of each group
+    into a multipolygon. The groups are dissolved in parallel.
+
+    Returns a record batch with the columns ``key`` and ``geometry``, containing one row for each distinct key
+    sorted by the key. Null keys form a group of their own.
+
+    :param arr: The cell array
+    :param keys: The array of keys to group the cells by. Must have the same length as the cell array.
+    :param radians: Generate geometries using radians instead of degrees
+    :param antimeridian_mode: Handling of polygons crossing the antimeridian.
+    """
+    return vector.cells_to_wkb_multipolygons_by_key(
+        _to_uint64_array(arr),
+        _to_arrow_array(keys),
+        radians=radians,
+        antimeridian_mode=antimeridian_mode,
+    )
 
 
 def cells_to_wkb_points(arr, radians: bool = False) -> Array:
@@ -90,7 +396,12 @@ def vertexes_to_wkb_points(arr, radians: bool = False) -> Array:
     return vector.vertexes_to_wkb_points(_to_uint64_array(arr), radians=radians)
 
 
-def directededges_to_wkb_linestrings(arr, radians: bool = False) -> Array:
+def directededges_to_wkb_linestrings(
+    arr,
+    radians: bool = False,
+    max_segment_length_m: Optional[float] = None,
+    max_segment_angle_deg: Optional[float] = None,
+) -> Array:
     """
     Convert directed edges to linestrings.
 
@@ -98,8 +409,122 @@ def directededges_to_wkb_linestrings(arr, radians: bool = False) -> Array:
 
     :param: arr: The directed edge array
     :param radians: Generate geometries using radians instead of degrees
+    :param max_segment_length_m: Insert points along the great circles between the vertices, so no segment is
+            longer than this length in meters.
+    :param max_segment_angle_deg: Like ``max_segment_length_m``, but limiting the angle spanned by the segments
+            to this number of degrees. Can not be combined with ``max_segment_length_m``.
+    """
+    return vector.directededges_to_wkb_linestrings(
+        _to_uint64_array(arr),
+        radians=radians,
+        max_segment_length_m=max_segment_length_m,
+        max_segment_angle_deg=max_segment_angle_deg,
+    )
+
+
+def cells_to_geoarrow_polygons(
+    arr,
+    radians: bool = False,
+    max_segment_length_m: Optional[float] = None,
+    max_segment_angle_deg: Optional[float] = None,
+) -> Array:
+    """
+    Convert cells to a native geoarrow polygon array.
+
+    In contrast to ``cells_to_wkb_polygons``, the geometries can be consumed without parsing WKB by libraries
+    supporting the geoarrow extension types.
+
+    The returned geometries in the output array will match the order of the input array.
+
+    :param: arr: The cell array
+    :param radians: Generate geometries using radians instead of degrees
+    :param max_segment_length_m: Insert points along the great circles between the vertices, so no segment is
+            longer than this length in meters.
+    :param max_segment_angle_deg: Like ``max_segment_length_m``, but limiting the angle spanned by the segments
+            to this number of degrees. Can not be combined with ``max_segment_length_m``.
+    """
+    return vector.cells_to_geoarrow_polygons(
+        _to_uint64_array(arr),
+        radians=radians,
+        max_segment_length_m=max_segment_length_m,
+        max_segment_angle_deg=max_segment_angle_deg,
+    )
+
+
+def celllists_to_geoarrow_multipolygons(
+    arr,
+    radians: bool = False,
+    max_segment_length_m: Optional[float] = None,
+    max_segment_angle_deg: Optional[float] = None,
+) -> Array:
+    """
+    Dissolve the cells of each list of a list array into a native geoarrow multipolygon array.
+
+    The returned geometries in the output array will match the order of the input array.
+
+    :param arr: The list array of cells, like it is returned by ``wkb_to_cells``.
+    :param radians: Generate geometries using radians instead of degrees
+    :param max_segment_length_m: Insert points along the great circles between the vertices, so no segment is
+            longer than this length in meters.
+    :param max_segment_angle_deg: Like ``max_segment_length_m``, but limiting the angle spanned by the segments
+            to this number of degrees. Can not be combined with ``max_segment_length_m``.
+    """
+    return vector.celllists_to_geoarrow_multipolygons(
+        _to_arrow_array(arr, DataType.large_list(DataType.uint64())),
+        radians=radians,
+        max_segment_length_m=max_segment_length_m,
+        max_segment_angle_deg=max_segment_angle_deg,
+    )
+
+
+def cells_to_geoarrow_points(arr, radians: bool = False) -> Array:
+    """
+    Convert cells to a native geoarrow point array using their centroids.
+
+    The returned geometries in the output array will match the order of the input array.
+
+    :param: arr: The cell array
+    :param radians: Generate geometries using radians instead of degrees
+    """
+    return vector.cells_to_geoarrow_points(_to_uint64_array(arr), radians=radians)
+
+
+def vertexes_to_geoarrow_points(arr, radians: bool = False) -> Array:
+    """
+    Convert vertexes to a native geoarrow point array.
+
+    The returned geometries in the output array will match the order of the input array.
+
+    :param: arr: The vertex array
+    :param radians: Generate geometries using radians instead of degrees
     """
-    return vector.directededges_to_wkb_linestrings(_to_uint64_array(arr), radians=radians)
+    return vector.vertexes_to_geoarrow_points(_to_uint64_array(arr), radians=radians)
+
+
+def directededges_to_geoarrow_linestrings(
+    arr,
+    radians: bool = False,
+    max_segment_length_m: Optional[float] = None,
+    max_segment_angle_deg: Optional[float] = None,
+) -> Array:
+    """
+    Convert directed edges to a native geoarrow linestring array.
+
+    The returned geometries in the output array will match the order of the input array.
+
+    :param: arr: The directed edge array
+    :param radians: Generate geometries using radians instead of degrees
+    :param max_segment_length_m: Insert points along the great circles between the vertices, so no segment is
+            longer than this length in meters.
+    :param max_segment_angle_deg: Like ``max_segment_length_m``, but limiting the angle spanned by the segments
+            to this number of degrees. Can not be combined with ``max_segment_length_m``.
+    """
+    return vector.directededges_to_geoarrow_linestrings(
+        _to_uint64_array(arr),
+        radians=radians,
+        max_segment_length_m=max_segment_length_m,
+        max_segment_angle_deg=max_segment_angle_deg,
+    )
 
 
 def wkb_to_cells(
@@ -108,9 +533,19 @@ def wkb_to_cells(
     containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
     compact: bool = False,
     flatten: bool = False,
+    antimeridian_handling: AntimeridianHandling = AntimeridianHandling.Heuristic,
+    min_resolution: Optional[int] = None,
+    max_cells: Optional[int] = None,
+    line_buffer_k: Optional[int] = None,
+    line_buffer_m: Optional[float] = None,
+    crs=None,
 ) -> Array:
     """
-    Convert a Series/Array/List of WKB values to H3 cells.
+    Convert a Series/Array/List of geometries to H3 cells.
+
+    Besides WKB, arrow arrays of WKT strings and the native geoarrow geometry types (``geoarrow.point``,
+    ``geoarrow.linestring``, ``geoarrow.polygon`` and their multi-variants) are accepted. Native geoarrow arrays are
+    detected by their extension type and converted without going through WKB.
 
     Unless ``flatten`` is set to True a list array will be returned, with the cells generated from a geometry being
     located at the same position as the geometry in the input array.
@@ -122,14 +557,136 @@ def wkb_to_cells(
     :param compact: Compact the returned cells by replacing cells with their parent cells when all children
             of that cell are part of the set.
     :param flatten: Return a non-nested cell array instead of a list array.
+    :param antimeridian_handling: Handling of polygons crossing the antimeridian or enclosing a pole.
+            See the AntimeridianHandling class.
+    :param min_resolution: Fill polygons adaptively using cells between ``min_resolution`` and ``resolution``.
+            Only cells along the boundaries of the polygons are generated at ``resolution``, the interior is
+            covered by the coarsest possible cells. This avoids generating all fine cells first as done
+            when using ``compact``. The result is always compacted.
+    :param max_cells: Return a covering of at most ``max_cells`` cells with resolutions between ``min_resolution``
+            (or 0) and ``resolution`` which minimizes the area outside of the geometry. This is useful for prefilters
+            and query ranges. Coarser cells are used when the covering at ``min_resolution`` exceeds ``max_cells``.
+            The containment mode and ``compact`` are ignored.
+    :param line_buffer_k: Widen the cells of lines to all cells within ``line_buffer_k`` grid steps of the cells
+            the lines pass through.
+    :param line_buffer_m: Widen the cells of lines to all cells with their centroid within ``line_buffer_m`` meters
+            of the line. The distance is measured along great circles, so the width of the corridor does not depend
+            on the latitude. Can not be combined with ``line_buffer_k``.
+    :param crs: The coordinate reference system of the input geometries: an EPSG code like ``3857`` or
+            ``"EPSG:32632"`` for the built-in Web Mercator and UTM transformations, or an object providing
+            ``to_wgs84(x, y)`` and ``from_wgs84(x, y)`` methods for numpy arrays of coordinates. Defaults to
+            WGS84 longitude/latitude.
     """
-    arr = _to_arrow_array(arr, DataType.binary())
+    arr = _to_geometry_array(arr)
     return vector.wkb_to_cells(
         arr,
         resolution,
         containment_mode=containment_mode,
         compact=compact,
         flatten=flatten,
+        antimeridian_handling=antimeridian_handling,
+        min_resolution=min_resolution,
+        max_cells=max_cells,
+        line_buffer_k=line_buffer_k,
+        line_buffer_m=line_buffer_m,
+        crs=crs,
+    )
+
+
+def wkb_to_cell_fractions(
+    arr,
+    resolution: int,
+    antimeridian_handling: AntimeridianHandling = AntimeridianHandling.Heuristic,
+) -> RecordBatch:
+    """
+    Convert a Series/Array/List of geometries to the H3 cells covering them together with the fraction of the area
+    of each cell which is located inside the geometry.
+
+    The fractions are computed by clipping the cells with the polygons of the geometries and comparing the
+    geodesic areas. Cells located completely inside a geometry have a fraction of 1.0. Points and lines do not
+    cover any area and do not result in any cells.
+
+    The input types are the same as accepted by ``wkb_to_cells``.
+
+    :param arr: The input array.
+    :param resolution: H3 resolution
+    :param antimeridian_handling: Handling of polygons crossing the antimeridian or enclosing a pole.
+            See the AntimeridianHandling class.
+    :returns: A record batch with the columns ``row_index`` (the position of the geometry in the input array),
+            ``cell`` and ``fraction``.
+    """
+    arr = _to_geometry_array(arr)
+    return vector.wkb_to_cell_fractions(arr, resolution, antimeridian_handling=antimeridian_handling)
+
+
+def wkb_areal_interpolation(
+    arr,
+    resolution: int,
+    extensive=None,
+    intensive=None,
+    antimeridian_handling: AntimeridianHandling = AntimeridianHandling.Heuristic,
+) -> RecordBatch:
+    """
+    Interpolate values of polygons onto the H3 cells covering them using the areas of the intersections of
+    the cells with the polygons.
+
+    * Extensive values (counts, totals, ...) are distributed over the cells of a polygon in proportion to the
+      intersected area. The values assigned to the cells of a polygon sum up to the value of the polygon.
+      Values of overlapping polygons are summed up.
+    * Intensive values (densities, rates, ...) are averaged over all polygons intersecting a cell, weighted by the
+      intersected area.
+
+    Null values are ignored, cells without any non-null values are set to null. The input geometry types are the
+    same as accepted by ``wkb_to_cells``.
+
+    :param arr: The input array.
+    :param resolution: H3 resolution
+    :param extensive: Optional table/record batch or dict of numeric columns with extensive values. The columns
+            must be of the same length as the input array.
+    :param intensive: Optional table/record batch or dict of numeric columns with intensive values. The columns
+            must be of the same length as the input array.
+    :param antimeridian_handling: Handling of polygons crossing the antimeridian or enclosing a pole.
+            See the AntimeridianHandling class.
+    :returns: A record batch with one row per cell. Besides the ``cell`` column it contains a column for each of the
+            extensive and intensive value columns. The names of all these columns must be unique.
+    """
+    arr = _to_geometry_array(arr)
+    return vector.wkb_areal_interpolation(
+        arr,
+        resolution,
+        extensive=_to_values_batch(extensive),
+        intensive=_to_values_batch(intensive),
+        antimeridian_handling=antimeridian_handling,
+    )
+
+
+def wkb_to_classified_cells(
+    arr,
+    resolution: int,
+    flatten: bool = False,
+    antimeridian_handling: AntimeridianHandling = AntimeridianHandling.Heuristic,
+) -> RecordBatch:
+    """
+    Convert a Series/Array/List of geometries to the H3 cells intersecting them and classify each cell as
+    interior or boundary cell.
+
+    Interior cells are located completely inside the polygons (the cells of ``ContainmentMode.ContainsBoundary``),
+    all other cells of ``ContainmentMode.IntersectsBoundary`` are boundary cells. The geometries are converted to
+    cells only once, exact checks are only done for the cells along the boundaries of the polygons. Cells of points
+    and lines are boundary cells.
+
+    The input types are the same as accepted by ``wkb_to_cells``.
+
+    :param arr: The input array.
+    :param resolution: H3 resolution
+    :param flatten: Return non-nested columns instead of list columns with one row per geometry.
+    :param antimeridian_handling: Handling of polygons crossing the antimeridian or enclosing a pole.
+            See the AntimeridianHandling class.
+    :returns: A record batch with the columns ``cell`` and ``is_interior``.
+    """
+    arr = _to_geometry_array(arr)
+    return vector.wkb_to_classified_cells(
+        arr, resolution, flatten=flatten, antimeridian_handling=antimeridian_handling
     )
 
 
@@ -138,6 +695,12 @@ def geometry_to_cells(
     resolution: int,
     containment_mode: ContainmentMode = ContainmentMode.ContainsCentroid,
     compact: bool = False,
+    antimeridian_handling: AntimeridianHandling = AntimeridianHandling.Heuristic,
+    min_resolution: Optional[int] = None,
+    max_cells: Optional[int] = None,
+    line_buffer_k: Optional[int] = None,
+    line_buffer_m: Optional[float] = None,
+    crs=None,
 ) -> Array:
     """
     Convert a single object which supports the python `__geo_interface__` protocol to H3 cells
@@ -148,19 +711,66 @@ def geometry_to_cells(
             See the ContainmentMode class.
     :param compact: Compact the returned cells by replacing cells with their parent cells when all children
             of that cell are part of the set.
+    :param antimeridian_handling: Handling of polygons crossing the antimeridian or enclosing a pole.
+            See the AntimeridianHandling class.
+    :param min_resolution: Fill polygons adaptively using cells between ``min_resolution`` and ``resolution``.
+            Only cells along the boundaries of the polygons are generated at ``resolution``, the interior is
+            covered by the coarsest possible cells. This avoids generating all fine cells first as done
+            when using ``compact``. The result is always compacted.
+    :param max_cells: Return a covering of at most ``max_cells`` cells with resolutions between ``min_resolution``
+            (or 0) and ``resolution`` which minimizes the area outside of the geometry. This is useful for prefilters
+            and query ranges. Coarser cells are used when the covering at ``min_resolution`` exceeds ``max_cells``.
+            The containment mode and ``compact`` are ignored.
+    :param line_buffer_k: Widen the cells of lines to all cells within ``line_buffer_k`` grid steps of the cells
+            the lines pass through.
+    :param line_buffer_m: Widen the cells of lines to all cells with their centroid within ``line_buffer_m`` meters
+            of the line. The distance is measured along great circles, so the width of the corridor does not depend
+            on the latitude. Can not be combined with ``line_buffer_k``.
+    :param crs: The coordinate reference system of the input geometries: an EPSG code like ``3857`` or
+            ``"EPSG:32632"`` for the built-in Web Mercator and UTM transformations, or an object providing
+            ``to_wgs84(x, y)`` and ``from_wgs84(x, y)`` methods for numpy arrays of coordinates. Defaults to
+            WGS84 longitude/latitude.
     """
-    return vector.geometry_to_cells(geom, resolution, containment_mode=containment_mode, compact=compact)
+    return vector.geometry_to_cells(
+        geom,
+        resolution,
+        containment_mode=containment_mode,
+        compact=compact,
+        antimeridian_handling=antimeridian_handling,
+        min_resolution=min_resolution,
+        max_cells=max_cells,
+        line_buffer_k=line_buffer_k,
+        line_buffer_m=line_buffer_m,
+        crs=crs,
+    )
 
 
 __all__ = [
     cells_to_coordinates.__name__,
+    cells_to_ecef_centroids.__name__,
+    cells_to_ecef_boundaries.__name__,
+    cells_to_ecef_mesh.__name__,
     coordinates_to_cells.__name__,
+    coordinates_to_cells_within_radius.__name__,
+    coordinates_to_hexbins.__name__,
+    coordinates_to_spacetime_bins.__name__,
+    coordinates_to_cell_sequences.__name__,
     cells_bounds.__name__,
     cells_bounds_arrays.__name__,
     cells_to_wkb_polygons.__name__,
+    cells_to_wkb_multipolygons_by_key.__name__,
+    celllists_to_wkb_multipolygons.__name__,
     cells_to_wkb_points.__name__,
     vertexes_to_wkb_points.__name__,
     directededges_to_wkb_linestrings.__name__,
+    cells_to_geoarrow_polygons.__name__,
+    celllists_to_geoarrow_multipolygons.__name__,
+    cells_to_geoarrow_points.__name__,
+    vertexes_to_geoarrow_points.__name__,
+    directededges_to_geoarrow_linestrings.__name__,
     wkb_to_cells.__name__,
+    wkb_to_cell_fractions.__name__,
+    wkb_areal_interpolation.__name__,
+    wkb_to_classified_cells.__name__,
     geometry_to_cells.__name__,
 ]
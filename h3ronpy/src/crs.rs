@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use geo_types::Coord;
+use h3arrow::crs::{CoordinateTransform, Crs};
+use h3arrow::error::Error;
+use numpy::PyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::error::IntoPyResult;
+
+/// A coordinate reference system passed in from python.
+///
+/// Accepted are EPSG codes as integers or `"EPSG:<code>"` strings for the built-in
+/// transformations, or objects providing `to_wgs84(x, y)` and `from_wgs84(x, y)` methods
+/// to transform numpy arrays of coordinates.
+pub struct PyCrs(pub Arc<dyn CoordinateTransform>);
+
+impl<'py> FromPyObject<'py> for PyCrs {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(code) = ob.extract::<u32>() {
+            return Ok(Self(Arc::new(Crs::from_epsg(code).into_pyresult()?)));
+        }
+        if let Ok(s) = ob.extract::<String>() {
+            return Ok(Self(Arc::new(s.parse::<Crs>().into_pyresult()?)));
+        }
+        if ob.hasattr("to_wgs84")? && ob.hasattr("from_wgs84")? {
+            return Ok(Self(Arc::new(PyCoordinateTransform {
+                obj: ob.clone().unbind(),
+            })));
+        }
+        Err(PyValueError::new_err(
+            "unsupported crs. Expected an EPSG code or an object with to_wgs84 and from_wgs84 methods",
+        ))
+    }
+}
+
+/// Coordinate transformation delegating to the methods of a python object.
+#[derive(Debug)]
+struct PyCoordinateTransform {
+    obj: PyObject,
+}
+
+impl PyCoordinateTransform {
+    fn call(&self, method: &str, coords: &mut [Coord]) -> Result<(), Error> {
+        Python::with_gil(|py| {
+            let (x, y): (Vec<f64>, Vec<f64>) = coords.iter().map(|c| (c.x, c.y)).unzip();
+            let (x, y): (Vec<f64>, Vec<f64>) = self
+                .obj
+                .call_method1(
+                    py,
+                    method,
+                    (
+                        PyArray1::from_vec_bound(py, x),
+                        PyArray1::from_vec_bound(py, y),
+                    ),
+                )?
+                .extract(py)?;
+            Ok::<_, PyErr>((x, y))
+        })
+        .map_err(|e| Error::CoordinateTransform(e.to_string()))
+        .and_then(|(x, y)| {
+            if x.len() != coords.len() || y.len() != coords.len() {
+                return Err(Error::LengthMismatch);
+            }
+            for (c, (x, y)) in coords.iter_mut().zip(x.into_iter().zip(y)) {
+                *c = Coord { x, y };
+            }
+            Ok(())
+        })
+    }
+}
+
+impl CoordinateTransform for PyCoordinateTransform {
+    fn transform_to_wgs84(&self, coords: &mut [Coord]) -> Result<(), Error> {
+        self.call("to_wgs84", coords)
+    }
+
+    fn transform_from_wgs84(&self, coords: &mut [Coord]) -> Result<(), Error> {
+        self.call("from_wgs84", coords)
+    }
+}
@@ -36,7 +36,10 @@ impl IntoPyErr for A3Error {
             | A3Error::NonParsableDirectedEdgeIndex
             | A3Error::NonParsableVertexIndex
             | A3Error::LengthMismatch
-            | A3Error::InvalidWKB => PyValueError::new_err(self.to_string()),
+            | A3Error::InvalidWKB
+            | A3Error::InvalidWKT
+            | A3Error::UnsupportedCrs(_)
+            | A3Error::CoordinateTransform(_) => PyValueError::new_err(self.to_string()),
             A3Error::IO(e) => e.into_pyerr(),
         }
     }
@@ -12,10 +12,13 @@ use pyo3::{prelude::*, wrap_pyfunction, Python};
 
 use crate::op::init_op_submodule;
 use crate::raster::init_raster_submodule;
-use crate::vector::{init_vector_submodule, PyContainmentMode};
+use crate::vector::{
+    init_vector_submodule, PyAntimeridianHandling, PyAntimeridianMode, PyContainmentMode,
+};
 
 mod array;
 mod arrow_interop;
+mod crs;
 mod error;
 mod op;
 mod raster;
@@ -46,6 +49,8 @@ fn h3ronpyrs(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     env_logger::init(); // run with the environment variable RUST_LOG set to "debug" for log output
 
     m.add_class::<PyContainmentMode>()?;
+    m.add_class::<PyAntimeridianMode>()?;
+    m.add_class::<PyAntimeridianHandling>()?;
     m.add_function(wrap_pyfunction!(version, m)?)?;
     m.add_function(wrap_pyfunction!(is_release_build, m)?)?;
 
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use arrow::array::Float64Array;
+use h3arrow::algorithm::ClusterOp;
+use pyo3::prelude::*;
+use pyo3_arrow::PyArray;
+
+use crate::array::PyCellArray;
+use crate::arrow_interop::*;
+use crate::error::IntoPyResult;
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, k, min_weight, weights = None))]
+pub(crate) fn cells_dbscan(
+    py: Python,
+    cellarray: PyCellArray,
+    k: u32,
+    min_weight: f64,
+    weights: Option<&Bound<PyAny>>,
+) -> PyResult<PyObject> {
+    let weights = weights.map(pyarray_to_native::<Float64Array>).transpose()?;
+    let cellindexarray = cellarray.into_inner();
+
+    let clusters = py
+        .allow_threads(|| cellindexarray.dbscan(k, min_weight, weights.as_ref()))
+        .into_pyresult()?;
+
+    PyArray::from_array_ref(Arc::new(clusters)).to_arro3(py)
+}
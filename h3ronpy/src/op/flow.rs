@@ -0,0 +1,102 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, LargeListArray, RecordBatch, UInt64Array};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::partition;
+use arrow::datatypes::{DataType, Field, Schema};
+use h3arrow::algorithm::FlowOp;
+use h3arrow::array::{CellIndexArray, H3ListArray};
+use h3arrow::export::h3o::{CellIndex, Resolution};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::{PyArray, PyRecordBatch};
+
+use crate::array::PyCellArray;
+use crate::error::IntoPyResult;
+
+/// Split the cells into sequences of consecutive rows with equal track ids.
+fn cell_sequences(
+    cellindexarray: CellIndexArray,
+    track_ids: Option<PyArray>,
+) -> PyArrowResult<H3ListArray<CellIndex>> {
+    let cells = UInt64Array::from(cellindexarray);
+    let tracks = match track_ids {
+        Some(track_ids) => {
+            let (track_ids, _) = track_ids.into_inner();
+            if track_ids.len() != cells.len() {
+                return Err(PyValueError::new_err(
+                    "cellarray and track_ids must be of the same length",
+                )
+                .into());
+            }
+            partition(&[track_ids])?.ranges()
+        }
+        None if cells.is_empty() => vec![],
+        None => vec![Range {
+            start: 0,
+            end: cells.len(),
+        }],
+    };
+
+    let listarray = LargeListArray::new(
+        Arc::new(Field::new("item", DataType::UInt64, true)),
+        OffsetBuffer::from_lengths(tracks.iter().map(|track| track.len())),
+        Arc::new(cells),
+        None,
+    );
+    Ok(H3ListArray::try_from(listarray).into_pyresult()?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, track_ids = None))]
+pub(crate) fn cells_to_edge_flows(
+    py: Python,
+    cellarray: PyCellArray,
+    track_ids: Option<PyArray>,
+) -> PyArrowResult<PyObject> {
+    let sequences = cell_sequences(cellarray.into_inner(), track_ids)?;
+    let flows = py
+        .allow_threads(|| sequences.edge_flows())
+        .into_pyresult()?;
+
+    let schema = Schema::new(vec![
+        Field::new("edge", DataType::UInt64, true),
+        Field::new("count", DataType::UInt64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from(flows.edges)),
+        Arc::new(flows.count),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, resolution, track_ids = None))]
+pub(crate) fn cells_to_od_flows(
+    py: Python,
+    cellarray: PyCellArray,
+    resolution: u8,
+    track_ids: Option<PyArray>,
+) -> PyArrowResult<PyObject> {
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    let sequences = cell_sequences(cellarray.into_inner(), track_ids)?;
+    let flows = py
+        .allow_threads(|| sequences.od_flows(resolution))
+        .into_pyresult()?;
+
+    let schema = Schema::new(vec![
+        Field::new("origin", DataType::UInt64, true),
+        Field::new("destination", DataType::UInt64, true),
+        Field::new("count", DataType::UInt64, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from(flows.origin)),
+        Arc::new(UInt64Array::from(flows.destination)),
+        Arc::new(flows.count),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
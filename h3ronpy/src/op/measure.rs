@@ -1,6 +1,10 @@
 use std::sync::Arc;
 
 use crate::array::PyCellArray;
+use crate::arrow_interop::pyarray_to_native;
+use crate::error::IntoPyResult;
+use h3arrow::algorithm::CoordinateArrays;
+use h3arrow::h3o::LatLng;
 use pyo3::prelude::*;
 use pyo3_arrow::PyArray;
 
@@ -24,3 +28,85 @@ pub(crate) fn cells_area_rads2(py: Python, cellarray: PyCellArray) -> PyResult<P
     let out = cellarray.as_ref().area_rads2();
     PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
 }
+
+macro_rules! impl_cells_distance {
+    ($paired_name:ident, $coordinates_name:ident, $point_name:ident, $paired:ident, $coordinates:ident, $point:ident) => {
+        #[pyfunction]
+        #[pyo3(signature = (cellarray, othercellarray))]
+        pub(crate) fn $paired_name(
+            py: Python,
+            cellarray: PyCellArray,
+            othercellarray: PyCellArray,
+        ) -> PyResult<PyObject> {
+            let out = cellarray
+                .as_ref()
+                .$paired(othercellarray.as_ref())
+                .into_pyresult()?;
+            PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
+        }
+
+        #[pyfunction]
+        #[pyo3(signature = (cellarray, latarray, lngarray, radians = false))]
+        pub(crate) fn $coordinates_name(
+            py: Python,
+            cellarray: PyCellArray,
+            latarray: &Bound<PyAny>,
+            lngarray: &Bound<PyAny>,
+            radians: bool,
+        ) -> PyResult<PyObject> {
+            let coordinates = CoordinateArrays {
+                lat: pyarray_to_native(latarray)?,
+                lng: pyarray_to_native(lngarray)?,
+            };
+            let out = cellarray
+                .as_ref()
+                .$coordinates(&coordinates, !radians)
+                .into_pyresult()?;
+            PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
+        }
+
+        #[pyfunction]
+        #[pyo3(signature = (cellarray, lat, lng, radians = false))]
+        pub(crate) fn $point_name(
+            py: Python,
+            cellarray: PyCellArray,
+            lat: f64,
+            lng: f64,
+            radians: bool,
+        ) -> PyResult<PyObject> {
+            let point = if radians {
+                LatLng::from_radians(lat, lng)
+            } else {
+                LatLng::new(lat, lng)
+            }
+            .into_pyresult()?;
+            let out = cellarray.as_ref().$point(point);
+            PyArray::from_array_ref(Arc::new(out)).to_arro3(py)
+        }
+    };
+}
+
+impl_cells_distance!(
+    cells_distance_m,
+    cells_distance_to_coordinates_m,
+    cells_distance_to_point_m,
+    distance_m,
+    distance_to_coordinates_m,
+    distance_to_point_m
+);
+impl_cells_distance!(
+    cells_distance_km,
+    cells_distance_to_coordinates_km,
+    cells_distance_to_point_km,
+    distance_km,
+    distance_to_coordinates_km,
+    distance_to_point_km
+);
+impl_cells_distance!(
+    cells_distance_rads,
+    cells_distance_to_coordinates_rads,
+    cells_distance_to_point_rads,
+    distance_rads,
+    distance_to_coordinates_rads,
+    distance_to_point_rads
+);
@@ -1,6 +1,8 @@
 use pyo3::prelude::*;
 
+mod cluster;
 mod compact;
+mod flow;
 mod localij;
 mod measure;
 mod neighbor;
@@ -17,6 +19,7 @@ pub fn init_op_submodule(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(neighbor::grid_disk_distances, m)?)?;
     m.add_function(wrap_pyfunction!(neighbor::grid_ring_distances, m)?)?;
     m.add_function(wrap_pyfunction!(neighbor::grid_disk_aggregate_k, m)?)?;
+    m.add_function(wrap_pyfunction!(neighbor::grid_disk_within_m, m)?)?;
     m.add_function(wrap_pyfunction!(string::cells_parse, m)?)?;
     m.add_function(wrap_pyfunction!(string::vertexes_parse, m)?)?;
     m.add_function(wrap_pyfunction!(string::directededges_parse, m)?)?;
@@ -31,8 +34,20 @@ pub fn init_op_submodule(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(measure::cells_area_m2, m)?)?;
     m.add_function(wrap_pyfunction!(measure::cells_area_km2, m)?)?;
     m.add_function(wrap_pyfunction!(measure::cells_area_rads2, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_m, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_to_coordinates_m, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_to_point_m, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_km, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_to_coordinates_km, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_to_point_km, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_rads, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_to_coordinates_rads, m)?)?;
+    m.add_function(wrap_pyfunction!(measure::cells_distance_to_point_rads, m)?)?;
     m.add_function(wrap_pyfunction!(localij::cells_to_localij, m)?)?;
     m.add_function(wrap_pyfunction!(localij::localij_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(cluster::cells_dbscan, m)?)?;
+    m.add_function(wrap_pyfunction!(flow::cells_to_edge_flows, m)?)?;
+    m.add_function(wrap_pyfunction!(flow::cells_to_od_flows, m)?)?;
 
     Ok(())
 }
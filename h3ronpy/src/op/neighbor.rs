@@ -1,9 +1,11 @@
 use arrow::array::{
-    Array, ArrayRef, GenericListArray, LargeListArray, PrimitiveArray, RecordBatch, UInt32Array,
+    Array, ArrayRef, GenericListArray, LargeListArray, PrimitiveArray, RecordBatch,
 };
 use arrow::datatypes::{Field, Schema};
-use h3arrow::algorithm::{GridDiskDistances, GridOp, KAggregationMethod};
-use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use h3arrow::algorithm::{
+    GridDiskDistances, GridDiskWithinOp, GridOp, KAggregationMethod, MetricDistanceMode,
+};
+use pyo3::exceptions::PyValueError;
 use pyo3::{PyObject, PyResult};
 use pyo3_arrow::error::PyArrowResult;
 use pyo3_arrow::{PyArray, PyRecordBatch};
@@ -47,7 +49,7 @@ pub(crate) fn grid_disk_distances(
         .grid_disk_distances(k)
         .into_pyresult()?;
 
-    return_griddiskdistances_table(py, griddiskdistances, flatten)
+    return_griddiskdistances_table(py, griddiskdistances, "k", flatten)
 }
 
 #[pyfunction]
@@ -67,12 +69,13 @@ pub(crate) fn grid_ring_distances(
         .grid_ring_distances(k_min, k_max)
         .into_pyresult()?;
 
-    return_griddiskdistances_table(py, griddiskdistances, flatten)
+    return_griddiskdistances_table(py, griddiskdistances, "k", flatten)
 }
 
 fn return_griddiskdistances_table(
     py: Python,
     griddiskdistances: GridDiskDistances<i64>,
+    distance_column_name: &str,
     flatten: bool,
 ) -> PyArrowResult<PyObject> {
     let (cells, distances): (ArrayRef, ArrayRef) = if flatten {
@@ -80,15 +83,7 @@ fn return_griddiskdistances_table(
             Arc::new(PrimitiveArray::from(
                 griddiskdistances.cells.into_flattened().into_pyresult()?,
             )),
-            Arc::new(
-                griddiskdistances
-                    .distances
-                    .values()
-                    .as_any()
-                    .downcast_ref::<UInt32Array>()
-                    .ok_or_else(|| PyRuntimeError::new_err("expected primitivearray<u32>"))
-                    .cloned()?,
-            ),
+            griddiskdistances.distances.values().clone(),
         )
     } else {
         (
@@ -99,13 +94,45 @@ fn return_griddiskdistances_table(
 
     let schema = Schema::new(vec![
         Field::new(DEFAULT_CELL_COLUMN_NAME, cells.data_type().clone(), true),
-        Field::new("k", distances.data_type().clone(), true),
+        Field::new(distance_column_name, distances.data_type().clone(), true),
     ]);
     let columns = vec![cells, distances];
     let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
     Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
 
+struct MetricDistanceModeWrapper(MetricDistanceMode);
+
+impl FromStr for MetricDistanceModeWrapper {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "centroid" => Ok(Self(MetricDistanceMode::Centroid)),
+            "boundary" => Ok(Self(MetricDistanceMode::Boundary)),
+            _ => Err(PyValueError::new_err("unknown distance mode")),
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, distance_m, distance_mode = "centroid", flatten = false))]
+pub(crate) fn grid_disk_within_m(
+    py: Python,
+    cellarray: PyCellArray,
+    distance_m: f64,
+    distance_mode: &str,
+    flatten: bool,
+) -> PyArrowResult<PyObject> {
+    let distance_mode = MetricDistanceModeWrapper::from_str(distance_mode)?;
+    let cellindexarray = cellarray.into_inner();
+    let griddiskdistances = py
+        .allow_threads(|| cellindexarray.grid_disk_within_m(distance_m, distance_mode.0))
+        .into_pyresult()?;
+
+    return_griddiskdistances_table(py, griddiskdistances, "distance_m", flatten)
+}
+
 struct KAggregationMethodWrapper(KAggregationMethod);
 
 impl FromStr for KAggregationMethodWrapper {
@@ -1,4 +1,4 @@
-use geo_types::Point;
+use geo_types::{Coord, LineString, Point, Polygon};
 use pyo3_arrow::PyArray;
 use std::hash::Hash;
 use std::iter::repeat;
@@ -9,9 +9,13 @@ use arrow::array::{
     Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, UInt16Array,
     UInt32Array, UInt64Array, UInt8Array,
 };
-use geo::{AffineOps, AffineTransform};
+use geo::{AffineOps, AffineTransform, GeodesicArea};
+use h3arrow::array::to_geo::AntimeridianMode;
 use h3arrow::array::CellIndexArray;
-use h3arrow::export::h3o::{CellIndex, Resolution};
+use h3arrow::crs::CoordinateTransform;
+use h3arrow::export::h3o::geom::{ContainmentMode, TilerBuilder};
+use h3arrow::export::h3o::{CellIndex, LatLng, Resolution};
+use hashbrown::HashMap;
 use ndarray::ArrayView2;
 use numpy::PyReadonlyArray2;
 use ordered_float::OrderedFloat;
@@ -19,6 +23,7 @@ use pyo3::exceptions::PyValueError;
 use pyo3::{prelude::*, wrap_pyfunction};
 
 use crate::arrow_interop::h3array_to_pyarray;
+use crate::crs::PyCrs;
 use crate::error::IntoPyResult;
 use crate::transform::Transform;
 
@@ -89,15 +94,27 @@ impl FromStr for ResolutionSearchMode {
 /// find the h3 resolution closed to the size of a pixel in an array
 /// of the given shape with the given transform
 #[pyfunction]
+#[pyo3(signature = (shape, transform, axis_order_str, search_mode_str, crs=None))]
 pub fn nearest_h3_resolution(
     shape: [usize; 2],
     transform: &Transform,
     axis_order_str: &str,
     search_mode_str: &str,
+    crs: Option<PyCrs>,
 ) -> PyResult<u8> {
     let axis_order = AxisOrder::from_str(axis_order_str)?;
-    sanitycheck_wgs84_bounds(&transform.inner, &axis_order.inner, &(shape[0], shape[1]))?;
     let search_mode = ResolutionSearchMode::from_str(search_mode_str)?;
+    if let Some(crs) = crs {
+        return projected_nearest_h3_resolution(
+            shape,
+            &transform.inner,
+            &axis_order.inner,
+            &search_mode.inner,
+            crs.0.as_ref(),
+        )
+        .map(Into::into);
+    }
+    sanitycheck_wgs84_bounds(&transform.inner, &axis_order.inner, &(shape[0], shape[1]))?;
 
     search_mode
         .inner
@@ -106,43 +123,207 @@ pub fn nearest_h3_resolution(
         .map(Into::into)
 }
 
-#[allow(clippy::type_complexity)]
+/// The size of a pixel of the array is determined at the center of the array, where the
+/// pixel is transformed to WGS84 to calculate its geodesic area.
+fn projected_nearest_h3_resolution(
+    shape: [usize; 2],
+    transform: &AffineTransform<f64>,
+    axis_order: &rasterh3::AxisOrder,
+    search_mode: &rasterh3::ResolutionSearchMode,
+    crs: &dyn CoordinateTransform,
+) -> PyResult<Resolution> {
+    let (width, height) = raster_size((shape[0], shape[1]), axis_order);
+    let (x, y) = ((width / 2) as f64, (height / 2) as f64);
+    let mut pixel = [
+        (x, y),
+        (x + 1.0, y),
+        (x + 1.0, y + 1.0),
+        (x, y + 1.0),
+        (x, y),
+    ]
+    .map(|c| transform.apply(Coord::from(c)));
+    crs.transform_to_wgs84(&mut pixel).into_pyresult()?;
+    let pixel_area_m2 =
+        Polygon::new(LineString::from(pixel.to_vec()), vec![]).geodesic_area_unsigned();
+
+    let resolutions = Resolution::range(Resolution::Zero, Resolution::Fifteen);
+    let resolution = match search_mode {
+        rasterh3::ResolutionSearchMode::MinDiff => resolutions.min_by(|a, b| {
+            (a.area_m2() - pixel_area_m2)
+                .abs()
+                .total_cmp(&(b.area_m2() - pixel_area_m2).abs())
+        }),
+        rasterh3::ResolutionSearchMode::SmallerThanPixel => {
+            resolutions.find(|resolution| resolution.area_m2() < pixel_area_m2)
+        }
+    };
+    Ok(resolution.unwrap_or(Resolution::Fifteen))
+}
+
+/// width and height of an array with the given dimensions
+fn raster_size(dim: (usize, usize), axis_order: &rasterh3::AxisOrder) -> (usize, usize) {
+    match axis_order {
+        rasterh3::AxisOrder::XY => dim,
+        rasterh3::AxisOrder::YX => (dim.1, dim.0),
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn raster_to_h3<'a, T>(
+    py: Python,
     arr: &'a ArrayView2<'a, T>,
     transform: &'a Transform,
     nodata_value: &'a Option<T>,
     h3_resolution: u8,
     axis_order_str: &str,
     compact: bool,
+    crs: Option<&PyCrs>,
 ) -> PyResult<(Vec<T>, Vec<CellIndex>)>
 where
-    T: PartialEq + Sized + Sync + Eq + Hash + Copy,
+    T: PartialEq + Sized + Send + Sync + Eq + Hash + Copy,
 {
     let axis_order = AxisOrder::from_str(axis_order_str)?;
+    let h3_resolution = Resolution::try_from(h3_resolution).into_pyresult()?;
+    if let Some(crs) = crs {
+        return projected_raster_to_h3(
+            py,
+            arr,
+            &transform.inner,
+            nodata_value,
+            h3_resolution,
+            &axis_order.inner,
+            compact,
+            crs.0.as_ref(),
+        );
+    }
     sanitycheck_wgs84_bounds(&transform.inner, &axis_order.inner, &arr.dim())?;
 
-    let h3_resolution = Resolution::try_from(h3_resolution).into_pyresult()?;
+    py.allow_threads(|| {
+        let conv =
+            rasterh3::H3Converter::new(arr, nodata_value, &transform.inner, axis_order.inner);
+
+        let mut values = vec![];
+        let mut cells = vec![];
+        for (value, cell_coverage) in conv.to_h3(h3_resolution, compact).into_pyresult()? {
+            let len_before = cells.len();
+            if compact {
+                cells.extend(cell_coverage.into_compacted_iter());
+            } else {
+                cells.extend(cell_coverage.into_uncompacted_iter(h3_resolution));
+            };
+            values.extend(repeat(*value).take(cells.len() - len_before));
+        }
+        Ok((values, cells))
+    })
+}
 
-    let conv = rasterh3::H3Converter::new(arr, nodata_value, &transform.inner, axis_order.inner);
-
-    let mut values = vec![];
-    let mut cells = vec![];
-    for (value, cell_coverage) in conv.to_h3(h3_resolution, compact).into_pyresult()? {
-        let len_before = cells.len();
-        if compact {
-            cells.extend(cell_coverage.into_compacted_iter());
-        } else {
-            cells.extend(cell_coverage.into_uncompacted_iter(h3_resolution));
-        };
-        values.extend(repeat(*value).take(cells.len() - len_before));
+/// Conversion of arrays using a coordinate reference system other than WGS84.
+///
+/// The outline of the array is transformed to WGS84 to find the cells covering the array,
+/// each cell then gets the value of the pixel located under its centroid. The coordinates
+/// are transformed in two batches while holding the GIL, as custom transformations call
+/// into python.
+#[allow(clippy::too_many_arguments)]
+fn projected_raster_to_h3<T>(
+    py: Python,
+    arr: &ArrayView2<T>,
+    transform: &AffineTransform<f64>,
+    nodata_value: &Option<T>,
+    h3_resolution: Resolution,
+    axis_order: &rasterh3::AxisOrder,
+    compact: bool,
+    crs: &dyn CoordinateTransform,
+) -> PyResult<(Vec<T>, Vec<CellIndex>)>
+where
+    T: PartialEq + Send + Sync + Eq + Hash + Copy,
+{
+    let (width, height) = raster_size(arr.dim(), axis_order);
+    if width == 0 || height == 0 {
+        return Ok((vec![], vec![]));
     }
-    Ok((values, cells))
+    let inverse = transform
+        .inverse()
+        .ok_or_else(|| PyValueError::new_err("the transform is not invertible"))?;
+
+    // the outline is densified to follow the curvature of the edges of the array in WGS84
+    const STEPS: usize = 64;
+    let (w, h) = (width as f64, height as f64);
+    let f = |i: usize| i as f64 / STEPS as f64;
+    let outline = (0..STEPS)
+        .map(|i| (f(i) * w, 0.0))
+        .chain((0..STEPS).map(|i| (w, f(i) * h)))
+        .chain((0..STEPS).map(|i| (w - f(i) * w, h)))
+        .chain((0..STEPS).map(|i| (0.0, h - f(i) * h)));
+    let mut outline = outline
+        .map(|c| transform.apply(Coord::from(c)))
+        .collect::<Vec<_>>();
+    crs.transform_to_wgs84(&mut outline).into_pyresult()?;
+
+    let (cells, mut centroids) = py.allow_threads(|| {
+        // the outline may span 180° of longitude or more, which the transmeridian heuristic
+        // of the tiler would misinterpret. It is split at the antimeridian instead.
+        let mut tiler = TilerBuilder::new(h3_resolution)
+            .containment_mode(ContainmentMode::IntersectsBoundary)
+            .disable_transmeridian_heuristic()
+            .build();
+        tiler
+            .add_batch(
+                AntimeridianMode::Split
+                    .apply_polygon(Polygon::new(LineString::from(outline), vec![])),
+            )
+            .into_pyresult()?;
+        let cells = tiler.into_coverage().collect::<Vec<_>>();
+        let centroids = cells
+            .iter()
+            .map(|cell| {
+                let ll = LatLng::from(*cell);
+                Coord {
+                    x: ll.lng(),
+                    y: ll.lat(),
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok::<_, PyErr>((cells, centroids))
+    })?;
+    crs.transform_from_wgs84(&mut centroids).into_pyresult()?;
+
+    py.allow_threads(|| {
+        let mut cells_by_value: HashMap<T, Vec<CellIndex>> = HashMap::new();
+        for (cell, centroid) in cells.into_iter().zip(centroids) {
+            let pixel = inverse.apply(centroid);
+            if !(pixel.x >= 0.0 && pixel.y >= 0.0 && pixel.x < w && pixel.y < h) {
+                continue;
+            }
+            let (x, y) = (pixel.x as usize, pixel.y as usize);
+            let value = match axis_order {
+                rasterh3::AxisOrder::XY => arr[[x, y]],
+                rasterh3::AxisOrder::YX => arr[[y, x]],
+            };
+            if nodata_value.as_ref() != Some(&value) {
+                cells_by_value.entry(value).or_default().push(cell);
+            }
+        }
+
+        let mut values = vec![];
+        let mut out_cells = vec![];
+        for (value, mut value_cells) in cells_by_value {
+            let len_before = out_cells.len();
+            if compact {
+                value_cells.sort_unstable();
+                out_cells.extend(CellIndex::compact(value_cells).into_pyresult()?);
+            } else {
+                out_cells.extend(value_cells);
+            }
+            values.extend(repeat(value).take(out_cells.len() - len_before));
+        }
+        Ok((values, out_cells))
+    })
 }
 
 macro_rules! make_raster_to_h3_variant {
     ($name:ident, $dtype:ty, $array_dtype:ty) => {
         #[pyfunction]
-        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None))]
+        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None, crs=None))]
         fn $name(
             py: Python,
             np_array: PyReadonlyArray2<$dtype>,
@@ -151,16 +332,19 @@ macro_rules! make_raster_to_h3_variant {
             axis_order_str: &str,
             compact: bool,
             nodata_value: Option<$dtype>,
+            crs: Option<PyCrs>,
         ) -> PyResult<(PyObject, PyObject)> {
             let arr = np_array.as_array();
-            let (values, cells) = py.allow_threads(|| raster_to_h3(
+            let (values, cells) = raster_to_h3(
+                py,
                 &arr,
                 transform,
                 &nodata_value,
                 h3_resolution,
                 axis_order_str,
                 compact,
-            ).map(|(values, cells)| (<$array_dtype>::from(values), cells)))?;
+                crs.as_ref(),
+            ).map(|(values, cells)| (<$array_dtype>::from(values), cells))?;
 
             let values = PyArray::from_array_ref(Arc::new(values)).to_arro3(py)?;
             let cells = h3array_to_pyarray(CellIndexArray::from(cells), py)?;
@@ -173,7 +357,7 @@ macro_rules! make_raster_to_h3_variant {
 macro_rules! make_raster_to_h3_float_variant {
     ($name:ident, $dtype:ty, $array_dtype:ty) => {
         #[pyfunction]
-        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None))]
+        #[pyo3(signature = (np_array, transform, h3_resolution, axis_order_str, compact, nodata_value=None, crs=None))]
         fn $name(
             py: Python,
             np_array: PyReadonlyArray2<$dtype>,
@@ -182,21 +366,24 @@ macro_rules! make_raster_to_h3_float_variant {
             axis_order_str: &str,
             compact: bool,
             nodata_value: Option<$dtype>,
+            crs: Option<PyCrs>,
         ) -> PyResult<(PyObject, PyObject)> {
             let arr = np_array.as_array();
             // create a copy with the values wrapped in ordered floats to
             // support the internal hashing
             let of_arr = arr.map(|v| OrderedFloat::from(*v));
-            let (values, cells) = py.allow_threads(|| raster_to_h3(
+            let (values, cells) = raster_to_h3(
+                py,
                 &of_arr.view(),
                 transform,
                 &nodata_value.map(OrderedFloat::from),
                 h3_resolution,
                 axis_order_str,
                 compact,
+                crs.as_ref(),
             ).map(|(values, cells)| (
                 <$array_dtype>::from(values.into_iter().map(|v| v.into_inner()).collect::<Vec<$dtype>>()),
-                cells)))?;
+                cells))?;
 
             let values = PyArray::from_array_ref(Arc::new(values)).to_arro3(py)?;
             let cells = h3array_to_pyarray(CellIndexArray::from(cells), py)?;
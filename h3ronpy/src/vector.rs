@@ -1,22 +1,46 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use arrow::array::{
-    ArrayRef, AsArray, Float64Array, GenericBinaryArray, GenericListArray, OffsetSizeTrait,
-    RecordBatch, UInt8Array,
+    Array, ArrayRef, AsArray, Float64Array, GenericListArray, OffsetSizeTrait, PrimitiveArray,
+    RecordBatch, UInt64Array, UInt8Array,
 };
 use arrow::buffer::NullBuffer;
-use arrow::datatypes::{DataType, Field, Schema};
-use geo::{BoundingRect, HasDimensions, LineString, ToRadians};
-use h3arrow::algorithm::ToCoordinatesOp;
-use h3arrow::array::from_geo::{ToCellIndexArray, ToCellListArray, ToCellsOptions};
-use h3arrow::array::to_geoarrow::{ToWKBLineStrings, ToWKBPoints, ToWKBPolygons};
-use h3arrow::array::{CellIndexArray, ResolutionArray};
-use h3arrow::export::geoarrow::array::{WKBArray, WKBBuilder, WKBCapacity};
+use arrow::compute::{cast, take};
+use arrow::datatypes::{
+    ArrowTimestampType, DataType, Field, Fields, Float64Type, Schema, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
+};
+use geo::{BoundingRect, HasDimensions, ToRadians};
+use h3arrow::algorithm::{
+    ArealInterpolationOp, CalendarUnit, CellsWithinRadiusOp, CoordinateArrays, DissolveOp,
+    HexbinAggregates, HexbinOp, HexbinResolution, SpaceTimeBinOp, TimeBucket, ToCoordinatesOp,
+    TrajectoryOp,
+};
+use h3arrow::array::from_geo::{
+    AntimeridianHandling, ClassifiedCellLists, LineBuffer, ToCellFractions, ToCellIndexArray,
+    ToCellListArray, ToCellsOptions, ToClassifiedCellListArray,
+};
+use h3arrow::array::from_geoarrow::WKTArray;
+use h3arrow::array::to_geo::{AntimeridianMode, Densify, ToDescendantPolygons, ToPolygons};
+use h3arrow::array::to_geoarrow::{
+    ToGeoArrowLineStrings, ToGeoArrowMultiPolygons, ToGeoArrowPoints, ToGeoArrowPolygons,
+    ToWKBLineStrings, ToWKBMultiPolygons, ToWKBPoints, ToWKBPolygons,
+};
+use h3arrow::array::{CellIndexArray, H3ListArray, ResolutionArray};
+use h3arrow::crs::{geometries_from_wgs84, geometries_to_wgs84, geometry_to_wgs84};
+use h3arrow::ecef::ToEcefOp;
+use h3arrow::export::geoarrow::array::{
+    LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray, PointArray,
+    PolygonArray, WKBArray, WKBBuilder, WKBCapacity,
+};
+use h3arrow::export::geoarrow::trait_::ArrayAccessor;
 use h3arrow::export::geoarrow::ArrayBase;
 use h3arrow::export::h3o::geom::ContainmentMode;
 use h3arrow::export::h3o::Resolution;
 use h3arrow::h3o::geom::dissolve;
-use h3arrow::h3o::LatLng;
+use h3arrow::h3o::{CellIndex, LatLng};
 use itertools::multizip;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -26,7 +50,9 @@ use pyo3_arrow::{PyArray, PyRecordBatch};
 
 use crate::array::{PyCellArray, PyDirectedEdgeArray, PyVertexArray};
 use crate::arrow_interop::*;
+use crate::crs::PyCrs;
 use crate::error::IntoPyResult;
+use crate::DEFAULT_CELL_COLUMN_NAME;
 
 /// Containment mode used to decide if a cell is contained in a polygon or not.
 ///
@@ -85,6 +111,66 @@ impl PyContainmentMode {
     }
 }
 
+/// Handling of polygons crossing the antimeridian.
+///
+/// Modes:
+///
+/// * Keep: Keep the coordinates unchanged. Polygons crossing the antimeridian span the whole globe
+///         when interpreted in a planar coordinate system.
+///
+/// * Shift: Shift the longitudes of polygons crossing the antimeridian into a continuous range. The
+///         longitudes of these polygons may exceed 180°.
+///
+/// * Split: Split polygons crossing the antimeridian into multipolygons with parts on both sides of
+///         the antimeridian.
+///
+/// Polygons enclosing a pole are extended to the pole in the Shift and Split modes.
+#[pyclass(name = "AntimeridianMode", eq, eq_int)]
+#[derive(Copy, Clone, Eq, PartialEq, Default)]
+pub enum PyAntimeridianMode {
+    #[default]
+    Keep,
+    Shift,
+    Split,
+}
+
+impl PyAntimeridianMode {
+    fn antimeridian_mode(&self) -> AntimeridianMode {
+        match self {
+            PyAntimeridianMode::Keep => AntimeridianMode::Keep,
+            PyAntimeridianMode::Shift => AntimeridianMode::Shift,
+            PyAntimeridianMode::Split => AntimeridianMode::Split,
+        }
+    }
+}
+
+/// Handling of polygons crossing the antimeridian or enclosing a pole when converting them to cells.
+///
+/// Modes:
+///
+/// * Heuristic: Polygons with an edge spanning more than 180° of longitude are assumed to cross the
+///         antimeridian.
+///
+/// * Split: Unwrap the longitudes of polygons, extend polygons enclosing a pole to the pole and split
+///         them at the antimeridian before converting them to cells. This supports longitudes outside
+///         of [-180, 180], like 170° to 190°, as well as polygons around the poles.
+#[pyclass(name = "AntimeridianHandling", eq, eq_int)]
+#[derive(Copy, Clone, Eq, PartialEq, Default)]
+pub enum PyAntimeridianHandling {
+    #[default]
+    Heuristic,
+    Split,
+}
+
+impl PyAntimeridianHandling {
+    fn antimeridian_handling(&self) -> AntimeridianHandling {
+        match self {
+            PyAntimeridianHandling::Heuristic => AntimeridianHandling::Heuristic,
+            PyAntimeridianHandling::Split => AntimeridianHandling::Split,
+        }
+    }
+}
+
 #[pyfunction]
 #[pyo3(signature = (cellarray,))]
 pub(crate) fn cells_bounds(py: Python<'_>, cellarray: PyCellArray) -> PyResult<Option<PyObject>> {
@@ -108,16 +194,21 @@ pub(crate) fn cells_bounds_arrays(py: Python, cellarray: PyCellArray) -> PyArrow
     let mut maxy_vec = vec![0.0f64; cellindexarray.len()];
     let mut validity_vec = vec![false; cellindexarray.len()];
 
-    for (cell, minx, miny, maxx, maxy, validity) in multizip((
-        cellindexarray.iter(),
+    // consistent with `cells_bounds`, the bounds of cells crossing the antimeridian extend beyond 180°
+    let polygons = py
+        .allow_threads(|| cellindexarray.to_polygons_antimeridian(true, AntimeridianMode::Shift))
+        .expect("polygon vec");
+
+    for (polygon, minx, miny, maxx, maxy, validity) in multizip((
+        polygons.iter(),
         minx_vec.iter_mut(),
         miny_vec.iter_mut(),
         maxx_vec.iter_mut(),
         maxy_vec.iter_mut(),
         validity_vec.iter_mut(),
     )) {
-        if let Some(cell) = cell {
-            if let Some(rect) = LineString::from(cell.boundary()).bounding_rect() {
+        if let Some(polygon) = polygon {
+            if let Some(rect) = polygon.bounding_rect() {
                 *validity = true;
                 *minx = rect.min().x;
                 *miny = rect.min().y;
@@ -171,6 +262,41 @@ pub(crate) fn cells_to_coordinates(
     Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
 
+#[pyfunction]
+#[pyo3(signature = (cellarray))]
+pub(crate) fn cells_to_ecef_centroids(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
+    let centroids = py
+        .allow_threads(|| cellarray.as_ref().to_ecef_centroids())
+        .into_pyresult()?;
+    PyArray::from_array_ref(Arc::new(centroids)).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray))]
+pub(crate) fn cells_to_ecef_boundaries(py: Python, cellarray: PyCellArray) -> PyResult<PyObject> {
+    let boundaries = py
+        .allow_threads(|| cellarray.as_ref().to_ecef_boundaries::<i64>())
+        .into_pyresult()?;
+    PyArray::from_array_ref(Arc::new(boundaries)).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, shared_vertices = true))]
+pub(crate) fn cells_to_ecef_mesh(
+    py: Python,
+    cellarray: PyCellArray,
+    shared_vertices: bool,
+) -> PyResult<(PyObject, PyObject, PyObject)> {
+    let mesh = py
+        .allow_threads(|| cellarray.as_ref().to_ecef_mesh(shared_vertices))
+        .into_pyresult()?;
+    Ok((
+        PyArray::from_array_ref(Arc::new(mesh.vertices)).to_arro3(py)?,
+        PyArray::from_array_ref(Arc::new(mesh.indices)).to_arro3(py)?,
+        PyArray::from_array_ref(Arc::new(mesh.triangle_cells)).to_arro3(py)?,
+    ))
+}
+
 #[pyfunction]
 #[pyo3(signature = (latarray, lngarray, resolution, radians = false))]
 pub(crate) fn coordinates_to_cells(
@@ -241,48 +367,557 @@ pub(crate) fn coordinates_to_cells(
 }
 
 #[pyfunction]
-#[pyo3(signature = (cellarray, radians = false, link_cells = false))]
+#[pyo3(signature = (latarray, lngarray, radiusarray, resolution, containment_mode = None, radians = false))]
+pub(crate) fn coordinates_to_cells_within_radius(
+    py: Python<'_>,
+    latarray: &Bound<PyAny>,
+    lngarray: &Bound<PyAny>,
+    radiusarray: &Bound<PyAny>,
+    resolution: u8,
+    containment_mode: Option<PyContainmentMode>,
+    radians: bool,
+) -> PyResult<PyObject> {
+    let coordinates = CoordinateArrays {
+        lat: pyarray_to_native(latarray)?,
+        lng: pyarray_to_native(lngarray)?,
+    };
+    let radiusarray: Float64Array = pyarray_to_native(radiusarray)?;
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    let containment_mode = containment_mode.unwrap_or_default().containment_mode();
+
+    let listarray: GenericListArray<i64> = py
+        .allow_threads(|| {
+            coordinates.cells_within_radius(&radiusarray, resolution, containment_mode, !radians)
+        })
+        .into_pyresult()?
+        .into();
+    PyArray::from_array_ref(Arc::new(listarray)).to_arro3(py)
+}
+
+/// Cast the columns of the `values` batch to `Float64`.
+fn value_columns(values: Option<PyRecordBatch>) -> PyArrowResult<(Fields, Vec<Float64Array>)> {
+    match values {
+        Some(values) => {
+            let values = values.into_inner();
+            let mut value_arrays = Vec::with_capacity(values.num_columns());
+            for column in values.columns() {
+                value_arrays.push(
+                    cast(column, &DataType::Float64)?
+                        .as_primitive::<Float64Type>()
+                        .clone(),
+                );
+            }
+            Ok((values.schema().fields().clone(), value_arrays))
+        }
+        None => Ok((Default::default(), vec![])),
+    }
+}
+
+/// Append the `<name>_sum`, `<name>_mean`, `<name>_min` and `<name>_max` columns for each value column.
+fn push_aggregate_columns(
+    fields: &mut Vec<Field>,
+    columns: &mut Vec<ArrayRef>,
+    value_fields: &Fields,
+    value_aggregates: Vec<HexbinAggregates>,
+) {
+    for (value_field, aggregates) in value_fields.iter().zip(value_aggregates) {
+        for (suffix, array) in [
+            ("sum", aggregates.sum),
+            ("mean", aggregates.mean),
+            ("min", aggregates.min),
+            ("max", aggregates.max),
+        ] {
+            fields.push(Field::new(
+                format!("{}_{}", value_field.name(), suffix),
+                DataType::Float64,
+                true,
+            ));
+            columns.push(Arc::new(array));
+        }
+    }
+}
+
+fn pycoordinates(
+    latarray: &Bound<PyAny>,
+    lngarray: &Bound<PyAny>,
+) -> PyArrowResult<CoordinateArrays> {
+    let coordinates = CoordinateArrays {
+        lat: pyarray_to_native(latarray)?,
+        lng: pyarray_to_native(lngarray)?,
+    };
+    if coordinates.lng.len() != coordinates.lat.len() {
+        return Err(
+            PyValueError::new_err("latarray and lngarray must be of the same length").into(),
+        );
+    }
+    Ok(coordinates)
+}
+
+enum PyHexbinResolution {
+    Fixed(Resolution),
+    PerCoordinate(ResolutionArray),
+}
+
+impl PyHexbinResolution {
+    fn extract(resolution: &Bound<PyAny>) -> PyResult<Self> {
+        if let Ok(resolution) = resolution.extract::<u8>() {
+            Ok(Self::Fixed(
+                Resolution::try_from(resolution).into_pyresult()?,
+            ))
+        } else {
+            Ok(Self::PerCoordinate(
+                ResolutionArray::try_from(pyarray_to_native::<UInt8Array>(resolution)?)
+                    .into_pyresult()?,
+            ))
+        }
+    }
+
+    fn as_hexbin_resolution(&self) -> HexbinResolution<'_> {
+        match self {
+            Self::Fixed(resolution) => HexbinResolution::Fixed(*resolution),
+            Self::PerCoordinate(resarray) => HexbinResolution::PerCoordinate(resarray),
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (latarray, lngarray, resolution, values = None, radians = false))]
+pub(crate) fn coordinates_to_hexbins(
+    py: Python<'_>,
+    latarray: &Bound<PyAny>,
+    lngarray: &Bound<PyAny>,
+    resolution: &Bound<PyAny>,
+    values: Option<PyRecordBatch>,
+    radians: bool,
+) -> PyArrowResult<PyObject> {
+    let coordinates = pycoordinates(latarray, lngarray)?;
+    let (value_fields, value_arrays) = value_columns(values)?;
+    let value_array_refs = value_arrays.iter().collect::<Vec<_>>();
+    let resolution = PyHexbinResolution::extract(resolution)?;
+
+    let hexbins = py
+        .allow_threads(|| {
+            coordinates.hexbin(
+                resolution.as_hexbin_resolution(),
+                &value_array_refs,
+                !radians,
+            )
+        })
+        .into_pyresult()?;
+
+    let mut fields = vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("count", DataType::UInt64, true),
+    ];
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from(hexbins.cells)),
+        Arc::new(hexbins.count),
+    ];
+    push_aggregate_columns(&mut fields, &mut columns, &value_fields, hexbins.values);
+
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+/// Evaluate `$body` with `$t` set to the arrow timestamp type matching the unit of the `$array`.
+macro_rules! with_timestamp_type {
+    ($array:expr, $t:ident => $body:expr) => {
+        match $array.data_type() {
+            DataType::Timestamp(TimeUnit::Second, _) => {
+                type $t = TimestampSecondType;
+                $body
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                type $t = TimestampMillisecondType;
+                $body
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                type $t = TimestampMicrosecondType;
+                $body
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                type $t = TimestampNanosecondType;
+                $body
+            }
+            dt => {
+                return Err(PyValueError::new_err(format!(
+                    "Expected an array of timestamps, found arrow array of type {:?}",
+                    dt
+                ))
+                .into())
+            }
+        }
+    };
+}
+
+fn parse_time_bucket(bucket: &Bound<PyAny>) -> PyResult<TimeBucket> {
+    if let Ok(microseconds) = bucket.extract::<u64>() {
+        return Ok(TimeBucket::Fixed(Duration::from_micros(microseconds)));
+    }
+    let unit = match bucket.extract::<String>()?.to_lowercase().as_str() {
+        "day" => CalendarUnit::Day,
+        "week" => CalendarUnit::Week,
+        "month" => CalendarUnit::Month,
+        "quarter" => CalendarUnit::Quarter,
+        "year" => CalendarUnit::Year,
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "unsupported calendar unit for time buckets: {}",
+                other
+            )))
+        }
+    };
+    Ok(TimeBucket::Calendar(unit))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spacetime_bins_batch<T: ArrowTimestampType>(
+    py: Python<'_>,
+    coordinates: &CoordinateArrays,
+    timestamps: &PrimitiveArray<T>,
+    resolution: &PyHexbinResolution,
+    bucket: TimeBucket,
+    value_fields: &Fields,
+    value_arrays: &[Float64Array],
+    radians: bool,
+) -> PyArrowResult<RecordBatch> {
+    let value_array_refs = value_arrays.iter().collect::<Vec<_>>();
+    let bins = py
+        .allow_threads(|| {
+            coordinates.spacetime_bin(
+                timestamps,
+                resolution.as_hexbin_resolution(),
+                bucket,
+                &value_array_refs,
+                !radians,
+            )
+        })
+        .into_pyresult()?;
+
+    let keys = bins.keys();
+    let mut fields = vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("bucket_start", bins.bucket_start.data_type().clone(), true),
+        Field::new("key", keys.data_type().clone(), true),
+        Field::new("count", DataType::UInt64, true),
+    ];
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from(bins.cells)),
+        Arc::new(bins.bucket_start),
+        Arc::new(keys),
+        Arc::new(bins.count),
+    ];
+    push_aggregate_columns(&mut fields, &mut columns, value_fields, bins.values);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (latarray, lngarray, timestamparray, resolution, bucket, values = None, radians = false))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn coordinates_to_spacetime_bins(
+    py: Python<'_>,
+    latarray: &Bound<PyAny>,
+    lngarray: &Bound<PyAny>,
+    timestamparray: PyArray,
+    resolution: &Bound<PyAny>,
+    bucket: &Bound<PyAny>,
+    values: Option<PyRecordBatch>,
+    radians: bool,
+) -> PyArrowResult<PyObject> {
+    let coordinates = pycoordinates(latarray, lngarray)?;
+    let (value_fields, value_arrays) = value_columns(values)?;
+    let resolution = PyHexbinResolution::extract(resolution)?;
+    let bucket = parse_time_bucket(bucket)?;
+    let (timestamps, _) = timestamparray.into_inner();
+
+    let batch = with_timestamp_type!(timestamps, T => spacetime_bins_batch(
+        py,
+        &coordinates,
+        timestamps.as_primitive::<T>(),
+        &resolution,
+        bucket,
+        &value_fields,
+        &value_arrays,
+        radians,
+    )?);
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+fn cell_sequences_batch<T: ArrowTimestampType>(
+    py: Python<'_>,
+    coordinates: &CoordinateArrays,
+    timestamps: &PrimitiveArray<T>,
+    track_ids: Option<&ArrayRef>,
+    resolution: Resolution,
+    fill_gaps: bool,
+    radians: bool,
+) -> PyArrowResult<RecordBatch> {
+    let sequences = py
+        .allow_threads(|| {
+            coordinates.to_cell_sequences(timestamps, track_ids, resolution, fill_gaps, !radians)
+        })
+        .into_pyresult()?;
+
+    let mut fields = vec![];
+    let mut columns: Vec<ArrayRef> = vec![];
+    if let Some(track_ids) = track_ids {
+        let run_track_ids = take(track_ids, &sequences.run_track_start(), None)?;
+        fields.push(Field::new("track_id", track_ids.data_type().clone(), true));
+        columns.push(run_track_ids);
+    }
+    fields.extend([
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, true),
+        Field::new("entry_time", sequences.entry_time.data_type().clone(), true),
+        Field::new("exit_time", sequences.exit_time.data_type().clone(), true),
+        Field::new("point_count", DataType::UInt64, true),
+    ]);
+    columns.extend([
+        Arc::new(UInt64Array::from(sequences.cells)) as ArrayRef,
+        Arc::new(sequences.entry_time),
+        Arc::new(sequences.exit_time),
+        Arc::new(sequences.point_count),
+    ]);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (latarray, lngarray, timestamparray, resolution, track_ids = None, fill_gaps = true, radians = false))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn coordinates_to_cell_sequences(
+    py: Python<'_>,
+    latarray: &Bound<PyAny>,
+    lngarray: &Bound<PyAny>,
+    timestamparray: PyArray,
+    resolution: u8,
+    track_ids: Option<PyArray>,
+    fill_gaps: bool,
+    radians: bool,
+) -> PyArrowResult<PyObject> {
+    let coordinates = pycoordinates(latarray, lngarray)?;
+    let resolution = Resolution::try_from(resolution).into_pyresult()?;
+    let track_ids = track_ids.map(|track_ids| track_ids.into_inner().0);
+    let (timestamps, _) = timestamparray.into_inner();
+
+    let batch = with_timestamp_type!(timestamps, T => cell_sequences_batch(
+        py,
+        &coordinates,
+        timestamps.as_primitive::<T>(),
+        track_ids.as_ref(),
+        resolution,
+        fill_gaps,
+        radians,
+    )?);
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, radians = false, link_cells = false, antimeridian_mode = None, crs = None, max_segment_length_m = None, max_segment_angle_deg = None, descendant_resolution = None))]
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn cells_to_wkb_polygons(
     py: Python,
     cellarray: PyCellArray,
     radians: bool,
     link_cells: bool,
+    antimeridian_mode: Option<PyAntimeridianMode>,
+    crs: Option<PyCrs>,
+    max_segment_length_m: Option<f64>,
+    max_segment_angle_deg: Option<f64>,
+    descendant_resolution: Option<u8>,
 ) -> PyResult<PyObject> {
     let cellindexarray = cellarray.into_inner();
     let use_degrees = !radians;
+    let antimeridian_mode = antimeridian_mode.unwrap_or_default().antimeridian_mode();
+    let densify = get_densify(max_segment_length_m, max_segment_angle_deg)?;
+    if radians && crs.is_some() {
+        return Err(PyValueError::new_err(
+            "radians can not be used together with a crs",
+        ));
+    }
+    let descendant_resolution = descendant_resolution
+        .map(|r| Resolution::try_from(r).into_pyresult())
+        .transpose()?;
+    if link_cells && descendant_resolution.is_some() {
+        return Err(PyValueError::new_err(
+            "link_cells can not be used together with descendant_resolution",
+        ));
+    }
+
+    // polygons using degrees to geometries with the densification and antimeridian mode applied
+    let to_geometry = |poly: geo_types::Polygon| {
+        let poly = match densify {
+            Some(densify) => densify.densify_polygon(&poly),
+            None => poly,
+        };
+        let mut geom = match antimeridian_mode {
+            AntimeridianMode::Keep => geo_types::Geometry::from(poly),
+            AntimeridianMode::Shift => {
+                geo_types::Geometry::from(antimeridian_mode.apply_polygon(poly).0.remove(0))
+            }
+            AntimeridianMode::Split => {
+                geo_types::Geometry::from(antimeridian_mode.apply_polygon(poly))
+            }
+        };
+        if radians {
+            geom.to_radians_in_place();
+        }
+        geom
+    };
+
+    let out: WKBArray<i64> = if link_cells || descendant_resolution.is_some() || crs.is_some() {
+        let mut geoms = py.allow_threads(|| {
+            if link_cells {
+                let mut cells = cellindexarray.iter().flatten().collect::<Vec<_>>();
+                cells.sort_unstable();
+                cells.dedup();
 
-    let out: WKBArray<i64> = py.allow_threads(|| {
-        if link_cells {
-            let mut cells = cellindexarray.iter().flatten().collect::<Vec<_>>();
-            cells.sort_unstable();
-            cells.dedup();
-
-            let geoms = dissolve(cells)
-                .into_pyresult()?
-                .into_iter()
-                .map(|mut poly| {
-                    if radians {
-                        poly.to_radians_in_place();
+                Ok(dissolve(cells)
+                    .into_pyresult()?
+                    .into_iter()
+                    .map(|poly| Some(to_geometry(poly)))
+                    .collect::<Vec<_>>())
+            } else if let Some(resolution) = descendant_resolution {
+                Ok(cellindexarray
+                    .to_descendant_polygons(resolution, true)
+                    .into_pyresult()?
+                    .into_iter()
+                    .map(|poly| poly.map(to_geometry))
+                    .collect::<Vec<_>>())
+            } else {
+                let polygons = match densify {
+                    Some(densify) => {
+                        cellindexarray.to_polygons_densified(true, antimeridian_mode, densify)
                     }
-                    Some(geo_types::Geometry::from(poly))
-                })
-                .collect::<Vec<_>>();
-            let mut builder = WKBBuilder::with_capacity(WKBCapacity::from_geometries(
-                geoms.iter().map(|v| v.as_ref()),
-            ));
-            builder.extend_from_iter(geoms.iter().map(|v| v.as_ref()));
-            Ok::<_, PyErr>(builder.finish())
-        } else {
-            Ok(cellindexarray
-                .to_wkb_polygons(use_degrees)
-                .expect("wkbarray"))
+                    None => cellindexarray.to_polygons_antimeridian(true, antimeridian_mode),
+                };
+                Ok::<_, PyErr>(
+                    polygons
+                        .into_pyresult()?
+                        .into_iter()
+                        .map(|mp| {
+                            mp.map(|mut mp| match antimeridian_mode {
+                                AntimeridianMode::Split => geo_types::Geometry::from(mp),
+                                _ => geo_types::Geometry::from(mp.0.remove(0)),
+                            })
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+        })?;
+
+        if let Some(crs) = crs {
+            geometries_from_wgs84(&mut geoms, crs.0.as_ref()).into_pyresult()?;
         }
-    })?;
+        py.allow_threads(|| geometries_to_wkb(&geoms))
+    } else {
+        py.allow_threads(|| match densify {
+            Some(densify) => cellindexarray
+                .to_wkb_polygons_densified(use_degrees, antimeridian_mode, densify)
+                .expect("wkbarray"),
+            None => cellindexarray
+                .to_wkb_polygons_antimeridian(use_degrees, antimeridian_mode)
+                .expect("wkbarray"),
+        })
+    };
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
+
+/// Write geometries as WKB.
+fn geometries_to_wkb(geoms: &[Option<geo_types::Geometry>]) -> WKBArray<i64> {
+    let mut builder = WKBBuilder::with_capacity(WKBCapacity::from_geometries(
+        geoms.iter().map(|v| v.as_ref()),
+    ));
+    builder.extend_from_iter(geoms.iter().map(|v| v.as_ref()));
+    builder.finish()
+}
+
+fn pyarray_to_celllistarray(array: PyArray) -> PyResult<H3ListArray<CellIndex>> {
+    let (array, _) = array.into_inner();
+    let listarray = array
+        .as_list_opt::<i64>()
+        .ok_or_else(|| PyValueError::new_err("expected a large list array of cells"))?
+        .clone();
+    H3ListArray::<CellIndex>::try_from(listarray).into_pyresult()
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, radians = false, antimeridian_mode = None, max_segment_length_m = None, max_segment_angle_deg = None))]
+pub(crate) fn celllists_to_wkb_multipolygons(
+    py: Python,
+    array: PyArray,
+    radians: bool,
+    antimeridian_mode: Option<PyAntimeridianMode>,
+    max_segment_length_m: Option<f64>,
+    max_segment_angle_deg: Option<f64>,
+) -> PyResult<PyObject> {
+    let celllistarray = pyarray_to_celllistarray(array)?;
+    let antimeridian_mode = antimeridian_mode.unwrap_or_default().antimeridian_mode();
+    let densify = get_densify(max_segment_length_m, max_segment_angle_deg)?;
+
+    let out: WKBArray<i64> = py
+        .allow_threads(|| match densify {
+            Some(densify) => {
+                celllistarray.to_wkb_multipolygons_densified(!radians, antimeridian_mode, densify)
+            }
+            None => celllistarray.to_wkb_multipolygons_antimeridian(!radians, antimeridian_mode),
+        })
+        .into_pyresult()?;
 
     let field = out.extension_field();
     PyArray::new(out.into_array_ref(), field).to_arro3(py)
 }
 
+#[pyfunction]
+#[pyo3(signature = (cellarray, keys, radians = false, antimeridian_mode = None))]
+pub(crate) fn cells_to_wkb_multipolygons_by_key(
+    py: Python,
+    cellarray: PyCellArray,
+    keys: PyArray,
+    radians: bool,
+    antimeridian_mode: Option<PyAntimeridianMode>,
+) -> PyArrowResult<PyObject> {
+    let cellindexarray = cellarray.into_inner();
+    let (keys, _) = keys.into_inner();
+    let antimeridian_mode = antimeridian_mode.unwrap_or_default().antimeridian_mode();
+
+    let (groups, wkbarray) = py.allow_threads(|| {
+        let groups = cellindexarray
+            .dissolve_by_key_antimeridian(keys.as_ref(), !radians, antimeridian_mode)
+            .into_pyresult()?;
+        let geoms = groups
+            .multipolygons
+            .iter()
+            .map(|mp| Some(geo_types::Geometry::from(mp.clone())))
+            .collect::<Vec<_>>();
+        let mut builder = WKBBuilder::<i64>::with_capacity(WKBCapacity::from_geometries(
+            geoms.iter().map(|v| v.as_ref()),
+        ));
+        builder.extend_from_iter(geoms.iter().map(|v| v.as_ref()));
+        Ok::<_, PyErr>((groups, builder.finish()))
+    })?;
+
+    let fields = vec![
+        Field::new("key", groups.keys.data_type().clone(), true),
+        wkbarray
+            .extension_field()
+            .as_ref()
+            .clone()
+            .with_name("geometry"),
+    ];
+    let columns: Vec<ArrayRef> = vec![groups.keys, wkbarray.into_array_ref()];
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
 #[pyfunction]
 #[pyo3(signature = (cellarray, radians = false))]
 pub(crate) fn cells_to_wkb_points(
@@ -320,17 +955,132 @@ pub(crate) fn vertexes_to_wkb_points(
 }
 
 #[pyfunction]
-#[pyo3(signature = (array, radians = false))]
+#[pyo3(signature = (array, radians = false, max_segment_length_m = None, max_segment_angle_deg = None))]
 pub(crate) fn directededges_to_wkb_linestrings(
     py: Python,
     array: PyDirectedEdgeArray,
     radians: bool,
+    max_segment_length_m: Option<f64>,
+    max_segment_angle_deg: Option<f64>,
 ) -> PyResult<PyObject> {
-    let out = py.allow_threads(|| {
-        array
+    let densify = get_densify(max_segment_length_m, max_segment_angle_deg)?;
+    let out = py.allow_threads(|| match densify {
+        Some(densify) => array
+            .as_ref()
+            .to_wkb_linestrings_densified::<i64>(!radians, densify)
+            .expect("wkbarray"),
+        None => array
             .as_ref()
             .to_wkb_linestrings::<i64>(!radians)
-            .expect("wkbarray")
+            .expect("wkbarray"),
+    });
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, radians = false, max_segment_length_m = None, max_segment_angle_deg = None))]
+pub(crate) fn cells_to_geoarrow_polygons(
+    py: Python,
+    cellarray: PyCellArray,
+    radians: bool,
+    max_segment_length_m: Option<f64>,
+    max_segment_angle_deg: Option<f64>,
+) -> PyResult<PyObject> {
+    let densify = get_densify(max_segment_length_m, max_segment_angle_deg)?;
+    let out = py.allow_threads(|| match densify {
+        Some(densify) => cellarray
+            .as_ref()
+            .to_geoarrow_polygons_densified::<i32>(!radians, densify)
+            .expect("polygonarray"),
+        None => cellarray
+            .as_ref()
+            .to_geoarrow_polygons::<i32>(!radians)
+            .expect("polygonarray"),
+    });
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, radians = false, max_segment_length_m = None, max_segment_angle_deg = None))]
+pub(crate) fn celllists_to_geoarrow_multipolygons(
+    py: Python,
+    array: PyArray,
+    radians: bool,
+    max_segment_length_m: Option<f64>,
+    max_segment_angle_deg: Option<f64>,
+) -> PyResult<PyObject> {
+    let celllistarray = pyarray_to_celllistarray(array)?;
+    let densify = get_densify(max_segment_length_m, max_segment_angle_deg)?;
+    let out = py
+        .allow_threads(|| match densify {
+            Some(densify) => celllistarray.to_geoarrow_multipolygons_densified(!radians, densify),
+            None => celllistarray.to_geoarrow_multipolygons(!radians),
+        })
+        .into_pyresult()?;
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (cellarray, radians = false))]
+pub(crate) fn cells_to_geoarrow_points(
+    py: Python,
+    cellarray: PyCellArray,
+    radians: bool,
+) -> PyResult<PyObject> {
+    let out = py.allow_threads(|| {
+        cellarray
+            .as_ref()
+            .to_geoarrow_points(!radians)
+            .expect("pointarray")
+    });
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (vertexarray, radians = false))]
+pub(crate) fn vertexes_to_geoarrow_points(
+    py: Python,
+    vertexarray: PyVertexArray,
+    radians: bool,
+) -> PyResult<PyObject> {
+    let out = py.allow_threads(|| {
+        vertexarray
+            .as_ref()
+            .to_geoarrow_points(!radians)
+            .expect("pointarray")
+    });
+
+    let field = out.extension_field();
+    PyArray::new(out.into_array_ref(), field).to_arro3(py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, radians = false, max_segment_length_m = None, max_segment_angle_deg = None))]
+pub(crate) fn directededges_to_geoarrow_linestrings(
+    py: Python,
+    array: PyDirectedEdgeArray,
+    radians: bool,
+    max_segment_length_m: Option<f64>,
+    max_segment_angle_deg: Option<f64>,
+) -> PyResult<PyObject> {
+    let densify = get_densify(max_segment_length_m, max_segment_angle_deg)?;
+    let out = py.allow_threads(|| match densify {
+        Some(densify) => array
+            .as_ref()
+            .to_geoarrow_lines_densified::<i32>(!radians, densify)
+            .expect("linestringarray"),
+        None => array
+            .as_ref()
+            .to_geoarrow_lines::<i32>(!radians)
+            .expect("linestringarray"),
     });
 
     let field = out.extension_field();
@@ -341,16 +1091,154 @@ fn get_to_cells_options(
     resolution: u8,
     containment_mode: Option<PyContainmentMode>,
     compact: bool,
+    antimeridian_handling: Option<PyAntimeridianHandling>,
+    min_resolution: Option<u8>,
+    max_cells: Option<usize>,
 ) -> PyResult<ToCellsOptions> {
     Ok(
         ToCellsOptions::new(Resolution::try_from(resolution).into_pyresult()?)
             .containment_mode(containment_mode.unwrap_or_default().containment_mode())
-            .compact(compact),
+            .compact(compact)
+            .antimeridian_handling(
+                antimeridian_handling
+                    .unwrap_or_default()
+                    .antimeridian_handling(),
+            )
+            .adaptive(
+                min_resolution
+                    .map(Resolution::try_from)
+                    .transpose()
+                    .into_pyresult()?,
+            )
+            .max_cells(max_cells),
     )
 }
 
+fn get_line_buffer(
+    line_buffer_k: Option<u32>,
+    line_buffer_m: Option<f64>,
+) -> PyResult<Option<LineBuffer>> {
+    match (line_buffer_k, line_buffer_m) {
+        (Some(_), Some(_)) => Err(PyValueError::new_err(
+            "line_buffer_k and line_buffer_m can not be used together",
+        )),
+        (Some(k), None) => Ok(Some(LineBuffer::K(k))),
+        (None, Some(m)) => Ok(Some(LineBuffer::Meters(m))),
+        (None, None) => Ok(None),
+    }
+}
+
+fn get_densify(
+    max_segment_length_m: Option<f64>,
+    max_segment_angle_deg: Option<f64>,
+) -> PyResult<Option<Densify>> {
+    match (max_segment_length_m, max_segment_angle_deg) {
+        (Some(_), Some(_)) => Err(PyValueError::new_err(
+            "max_segment_length_m and max_segment_angle_deg can not be used together",
+        )),
+        (Some(m), None) => Densify::max_length_m(m).into_pyresult().map(Some),
+        (None, Some(deg)) => Densify::max_angle_rads(deg.to_radians())
+            .into_pyresult()
+            .map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Dispatch on the type of a geometry input array: native geoarrow arrays are detected by
+/// their extension type, binary arrays are read as WKB and string arrays as WKT.
+///
+/// `$body` is evaluated with `$geomarray` bound to the converted array and `$offset` being
+/// the offset type of the cell lists generated from it.
+macro_rules! with_geometry_array {
+    (@native $array_type:ty, $array:ident, $field:ident, |$geomarray:ident, $offset:ident| $body:expr) => {{
+        #[allow(dead_code)]
+        type $offset = i64;
+        let $geomarray = <$array_type>::try_from(($array.as_ref(), $field.as_ref()))
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        $body
+    }};
+    (@with $offset_type:ty, $value:expr, |$geomarray:ident, $offset:ident| $body:expr) => {{
+        #[allow(dead_code)]
+        type $offset = $offset_type;
+        let $geomarray = $value;
+        $body
+    }};
+    ($array:ident, $field:ident, |$geomarray:ident, $offset:ident| $body:expr) => {{
+        let extension_name = $field
+            .metadata()
+            .get("ARROW:extension:name")
+            .map(String::as_str);
+
+        match (extension_name, $array.data_type()) {
+            (Some("geoarrow.point"), _) => {
+                with_geometry_array!(@native PointArray, $array, $field, |$geomarray, $offset| $body)
+            }
+            (Some("geoarrow.linestring"), _) => {
+                with_geometry_array!(@native LineStringArray, $array, $field, |$geomarray, $offset| $body)
+            }
+            (Some("geoarrow.polygon"), _) => {
+                with_geometry_array!(@native PolygonArray, $array, $field, |$geomarray, $offset| $body)
+            }
+            (Some("geoarrow.multipoint"), _) => {
+                with_geometry_array!(@native MultiPointArray, $array, $field, |$geomarray, $offset| $body)
+            }
+            (Some("geoarrow.multilinestring"), _) => {
+                with_geometry_array!(@native MultiLineStringArray, $array, $field, |$geomarray, $offset| $body)
+            }
+            (Some("geoarrow.multipolygon"), _) => {
+                with_geometry_array!(@native MultiPolygonArray, $array, $field, |$geomarray, $offset| $body)
+            }
+            (_, DataType::Binary) => with_geometry_array!(
+                @with i32,
+                WKBArray::new($array.as_binary::<i32>().clone(), Default::default()),
+                |$geomarray, $offset| $body
+            ),
+            (_, DataType::LargeBinary) => with_geometry_array!(
+                @with i64,
+                WKBArray::new($array.as_binary::<i64>().clone(), Default::default()),
+                |$geomarray, $offset| $body
+            ),
+            (_, DataType::BinaryView) => with_geometry_array!(
+                @with i64,
+                WKBArray::new(
+                    cast($array.as_ref(), &DataType::LargeBinary)
+                        .into_pyresult()?
+                        .as_binary::<i64>()
+                        .clone(),
+                    Default::default(),
+                ),
+                |$geomarray, $offset| $body
+            ),
+            (_, DataType::Utf8) => with_geometry_array!(
+                @with i32,
+                WKTArray::new($array.as_string::<i32>().clone()),
+                |$geomarray, $offset| $body
+            ),
+            (_, DataType::LargeUtf8) => with_geometry_array!(
+                @with i64,
+                WKTArray::new($array.as_string::<i64>().clone()),
+                |$geomarray, $offset| $body
+            ),
+            (_, DataType::Utf8View) => with_geometry_array!(
+                @with i64,
+                WKTArray::new(
+                    cast($array.as_ref(), &DataType::LargeUtf8)
+                        .into_pyresult()?
+                        .as_string::<i64>()
+                        .clone(),
+                ),
+                |$geomarray, $offset| $body
+            ),
+            _ => Err(PyValueError::new_err(
+                "unsupported array type for geometry input. Expected WKB, WKT or a geoarrow geometry array",
+            )
+            .into()),
+        }
+    }};
+}
+
 #[pyfunction]
-#[pyo3(signature = (array, resolution, containment_mode = None, compact = false, flatten = false))]
+#[pyo3(signature = (array, resolution, containment_mode = None, compact = false, flatten = false, antimeridian_handling = None, min_resolution = None, max_cells = None, line_buffer_k = None, line_buffer_m = None, crs = None))]
 pub(crate) fn wkb_to_cells(
     py: Python,
     array: PyArray,
@@ -358,45 +1246,150 @@ pub(crate) fn wkb_to_cells(
     containment_mode: Option<PyContainmentMode>,
     compact: bool,
     flatten: bool,
+    antimeridian_handling: Option<PyAntimeridianHandling>,
+    min_resolution: Option<u8>,
+    max_cells: Option<usize>,
+    line_buffer_k: Option<u32>,
+    line_buffer_m: Option<f64>,
+    crs: Option<PyCrs>,
 ) -> PyResult<PyObject> {
-    let options = get_to_cells_options(resolution, containment_mode, compact)?;
-
-    match array.field().data_type() {
-        DataType::Binary => generic_wkb_to_cells(
-            py,
-            array.array().as_binary::<i32>().clone(),
-            flatten,
-            &options,
-        ),
-        DataType::LargeBinary => generic_wkb_to_cells(
-            py,
-            array.array().as_binary::<i64>().clone(),
-            flatten,
-            &options,
-        ),
-        _ => Err(PyValueError::new_err(
-            "unsupported array type for WKB input",
-        )),
+    let options = get_to_cells_options(
+        resolution,
+        containment_mode,
+        compact,
+        antimeridian_handling,
+        min_resolution,
+        max_cells,
+    )?
+    .line_buffer(get_line_buffer(line_buffer_k, line_buffer_m)?);
+
+    let (array, field) = array.into_inner();
+    with_geometry_array!(array, field, |geomarray, O| match crs {
+        Some(crs) => {
+            let mut geoms = (0..geomarray.len())
+                .map(|pos| geomarray.get_as_geo(pos).map(geo_types::Geometry::from))
+                .collect::<Vec<_>>();
+
+            geometries_to_wgs84(&mut geoms, crs.0.as_ref()).into_pyresult()?;
+            geometries_to_cells::<O, _>(py, geoms.as_slice(), flatten, &options)
+        }
+        None => geometries_to_cells::<O, _>(py, geomarray, flatten, &options),
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, resolution, antimeridian_handling = None))]
+pub(crate) fn wkb_to_cell_fractions(
+    py: Python,
+    array: PyArray,
+    resolution: u8,
+    antimeridian_handling: Option<PyAntimeridianHandling>,
+) -> PyArrowResult<PyObject> {
+    let options = get_to_cells_options(resolution, None, false, antimeridian_handling, None, None)?;
+
+    let (array, field) = array.into_inner();
+    let fractions = with_geometry_array!(array, field, |geomarray, O| {
+        py.allow_threads(|| geomarray.to_cell_fractions(&options))
+            .into_pyresult()
+    })?;
+
+    let schema = Schema::new(vec![
+        Field::new("row_index", DataType::UInt64, false),
+        Field::new(DEFAULT_CELL_COLUMN_NAME, DataType::UInt64, false),
+        Field::new("fraction", DataType::Float64, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(fractions.row_index),
+        Arc::new(UInt64Array::from(fractions.cells)),
+        Arc::new(fractions.fractions),
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (array, resolution, extensive = None, intensive = None, antimeridian_handling = None))]
+pub(crate) fn wkb_areal_interpolation(
+    py: Python,
+    array: PyArray,
+    resolution: u8,
+    extensive: Option<PyRecordBatch>,
+    intensive: Option<PyRecordBatch>,
+    antimeridian_handling: Option<PyAntimeridianHandling>,
+) -> PyArrowResult<PyObject> {
+    let options = get_to_cells_options(resolution, None, false, antimeridian_handling, None, None)?;
+    let (array, field) = array.into_inner();
+    let (extensive_fields, extensive_arrays) = value_columns(extensive)?;
+    let (intensive_fields, intensive_arrays) = value_columns(intensive)?;
+    let mut names = vec![DEFAULT_CELL_COLUMN_NAME];
+    for value_field in extensive_fields.iter().chain(intensive_fields.iter()) {
+        if names.contains(&value_field.name().as_str()) {
+            return Err(PyValueError::new_err(format!(
+                "the column name '{}' is used more than once",
+                value_field.name()
+            ))
+            .into());
+        }
+        names.push(value_field.name().as_str());
+    }
+    if extensive_arrays
+        .iter()
+        .chain(intensive_arrays.iter())
+        .any(|values| values.len() != array.len())
+    {
+        return Err(PyValueError::new_err(
+            "the value columns must have the same length as the geometry array",
+        )
+        .into());
     }
+    let extensive_refs = extensive_arrays.iter().collect::<Vec<_>>();
+    let intensive_refs = intensive_arrays.iter().collect::<Vec<_>>();
+
+    let interpolated = with_geometry_array!(array, field, |geomarray, O| {
+        py.allow_threads(|| {
+            geomarray.areal_interpolation(&extensive_refs, &intensive_refs, &options)
+        })
+        .into_pyresult()
+    })?;
+
+    let mut fields = vec![Field::new(
+        DEFAULT_CELL_COLUMN_NAME,
+        DataType::UInt64,
+        false,
+    )];
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(UInt64Array::from(interpolated.cells))];
+    for (value_field, values) in extensive_fields
+        .iter()
+        .zip(interpolated.extensive)
+        .chain(intensive_fields.iter().zip(interpolated.intensive))
+    {
+        fields.push(Field::new(value_field.name(), DataType::Float64, true));
+        columns.push(Arc::new(values));
+    }
+
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
 }
 
-fn generic_wkb_to_cells<O: OffsetSizeTrait>(
+fn geometries_to_cells<O, A>(
     py: Python,
-    binarray: GenericBinaryArray<O>,
+    geomarray: A,
     flatten: bool,
     options: &ToCellsOptions,
-) -> PyResult<PyObject> {
-    let wkbarray = WKBArray::new(binarray, Default::default());
-
+) -> PyResult<PyObject>
+where
+    O: OffsetSizeTrait,
+    A: ToCellIndexArray + ToCellListArray<O> + Sync,
+{
     if flatten {
         let cells = py
-            .allow_threads(|| wkbarray.to_cellindexarray(options))
+            .allow_threads(|| geomarray.to_cellindexarray(options))
             .into_pyresult()?;
 
         h3array_to_pyarray(cells, py)
     } else {
         let listarray: GenericListArray<O> = py
-            .allow_threads(|| wkbarray.to_celllistarray(options))
+            .allow_threads(|| geomarray.to_celllistarray(options))
             .into_pyresult()?
             .into();
         PyArray::from_array_ref(Arc::new(listarray)).to_arro3(py)
@@ -404,21 +1397,91 @@ fn generic_wkb_to_cells<O: OffsetSizeTrait>(
 }
 
 #[pyfunction]
-#[pyo3(signature = (obj, resolution, containment_mode = None, compact = false))]
+#[pyo3(signature = (array, resolution, flatten = false, antimeridian_handling = None))]
+pub(crate) fn wkb_to_classified_cells(
+    py: Python,
+    array: PyArray,
+    resolution: u8,
+    flatten: bool,
+    antimeridian_handling: Option<PyAntimeridianHandling>,
+) -> PyArrowResult<PyObject> {
+    let options = get_to_cells_options(resolution, None, false, antimeridian_handling, None, None)?;
+
+    let (array, field) = array.into_inner();
+    let batch = with_geometry_array!(array, field, |geomarray, O| {
+        let classified = py
+            .allow_threads(|| {
+                ToClassifiedCellListArray::<O>::to_classified_celllistarray(&geomarray, &options)
+            })
+            .into_pyresult()?;
+        classified_cells_to_recordbatch(classified, flatten)
+    })?;
+    Ok(PyRecordBatch::new(batch).to_arro3(py)?)
+}
+
+/// Build a record batch with the columns `cell` and `is_interior`. Unless `flatten` is set, both
+/// columns are list arrays with one row per geometry.
+fn classified_cells_to_recordbatch<O: OffsetSizeTrait>(
+    classified: ClassifiedCellLists<O>,
+    flatten: bool,
+) -> PyArrowResult<RecordBatch> {
+    let listarray: GenericListArray<O> = classified.cells.into();
+    let (cells, is_interior): (ArrayRef, ArrayRef) = if flatten {
+        (listarray.values().clone(), Arc::new(classified.is_interior))
+    } else {
+        let is_interior = GenericListArray::<O>::try_new(
+            Arc::new(Field::new("item", DataType::Boolean, false)),
+            listarray.offsets().clone(),
+            Arc::new(classified.is_interior),
+            listarray.nulls().cloned(),
+        )?;
+        (Arc::new(listarray), Arc::new(is_interior))
+    };
+
+    let schema = Schema::new(vec![
+        Field::new(DEFAULT_CELL_COLUMN_NAME, cells.data_type().clone(), true),
+        Field::new("is_interior", is_interior.data_type().clone(), true),
+    ]);
+    Ok(RecordBatch::try_new(
+        Arc::new(schema),
+        vec![cells, is_interior],
+    )?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (obj, resolution, containment_mode = None, compact = false, antimeridian_handling = None, min_resolution = None, max_cells = None, line_buffer_k = None, line_buffer_m = None, crs = None))]
 pub(crate) fn geometry_to_cells(
     py: Python<'_>,
     obj: py_geo_interface::Geometry,
     resolution: u8,
     containment_mode: Option<PyContainmentMode>,
     compact: bool,
+    antimeridian_handling: Option<PyAntimeridianHandling>,
+    min_resolution: Option<u8>,
+    max_cells: Option<usize>,
+    line_buffer_k: Option<u32>,
+    line_buffer_m: Option<f64>,
+    crs: Option<PyCrs>,
 ) -> PyResult<PyObject> {
     if obj.0.is_empty() {
         return h3array_to_pyarray(CellIndexArray::new_null(0), py);
     }
-    let options = get_to_cells_options(resolution, containment_mode, compact)?;
+    let mut geom = obj.0;
+    if let Some(crs) = crs {
+        geometry_to_wgs84(&mut geom, crs.0.as_ref()).into_pyresult()?;
+    }
+    let options = get_to_cells_options(
+        resolution,
+        containment_mode,
+        compact,
+        antimeridian_handling,
+        min_resolution,
+        max_cells,
+    )?
+    .line_buffer(get_line_buffer(line_buffer_k, line_buffer_m)?);
     let cellindexarray = py.allow_threads(|| {
         Ok::<_, PyErr>(CellIndexArray::from(
-            h3arrow::array::from_geo::geometry_to_cells(&obj.0, &options).into_pyresult()?,
+            h3arrow::array::from_geo::geometry_to_cells(&geom, &options).into_pyresult()?,
         ))
     })?;
     h3array_to_pyarray(cellindexarray, py)
@@ -426,14 +1489,31 @@ pub(crate) fn geometry_to_cells(
 
 pub fn init_vector_submodule(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(cells_to_coordinates, m)?)?;
+    m.add_function(wrap_pyfunction!(cells_to_ecef_centroids, m)?)?;
+    m.add_function(wrap_pyfunction!(cells_to_ecef_boundaries, m)?)?;
+    m.add_function(wrap_pyfunction!(cells_to_ecef_mesh, m)?)?;
     m.add_function(wrap_pyfunction!(cells_bounds, m)?)?;
     m.add_function(wrap_pyfunction!(cells_bounds_arrays, m)?)?;
     m.add_function(wrap_pyfunction!(cells_to_wkb_polygons, m)?)?;
+    m.add_function(wrap_pyfunction!(cells_to_wkb_multipolygons_by_key, m)?)?;
+    m.add_function(wrap_pyfunction!(celllists_to_wkb_multipolygons, m)?)?;
     m.add_function(wrap_pyfunction!(cells_to_wkb_points, m)?)?;
     m.add_function(wrap_pyfunction!(vertexes_to_wkb_points, m)?)?;
     m.add_function(wrap_pyfunction!(directededges_to_wkb_linestrings, m)?)?;
+    m.add_function(wrap_pyfunction!(cells_to_geoarrow_polygons, m)?)?;
+    m.add_function(wrap_pyfunction!(celllists_to_geoarrow_multipolygons, m)?)?;
+    m.add_function(wrap_pyfunction!(cells_to_geoarrow_points, m)?)?;
+    m.add_function(wrap_pyfunction!(vertexes_to_geoarrow_points, m)?)?;
+    m.add_function(wrap_pyfunction!(directededges_to_geoarrow_linestrings, m)?)?;
     m.add_function(wrap_pyfunction!(wkb_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(wkb_to_cell_fractions, m)?)?;
+    m.add_function(wrap_pyfunction!(wkb_areal_interpolation, m)?)?;
+    m.add_function(wrap_pyfunction!(wkb_to_classified_cells, m)?)?;
     m.add_function(wrap_pyfunction!(geometry_to_cells, m)?)?;
     m.add_function(wrap_pyfunction!(coordinates_to_cells, m)?)?;
+    m.add_function(wrap_pyfunction!(coordinates_to_cells_within_radius, m)?)?;
+    m.add_function(wrap_pyfunction!(coordinates_to_hexbins, m)?)?;
+    m.add_function(wrap_pyfunction!(coordinates_to_spacetime_bins, m)?)?;
+    m.add_function(wrap_pyfunction!(coordinates_to_cell_sequences, m)?)?;
     Ok(())
 }
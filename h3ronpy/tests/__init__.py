@@ -8,3 +8,8 @@ TESTDATA_PATH = Path(__file__).parent.parent / "data"
 def load_africa() -> gpd.GeoDataFrame:
     world = gpd.read_file(TESTDATA_PATH / "naturalearth_110m_admin_0_countries.fgb")
     return world[world["CONTINENT"] == "Africa"]
+
+
+def load_country(name: str):
+    world = gpd.read_file(TESTDATA_PATH / "naturalearth_110m_admin_0_countries.fgb")
+    return world[world["NAME"] == name].geometry.iloc[0]
@@ -0,0 +1,25 @@
+import h3.api.numpy_int as h3
+import numpy as np
+from arro3.core import Array, DataType
+from h3ronpy import cells_dbscan
+
+
+def test_cells_dbscan():
+    center = h3.latlng_to_cell(10.3, 45.1, 8)
+    lonely = h3.latlng_to_cell(-20.3, 12.1, 8)
+    cells = np.array(list(h3.grid_disk(center, 1)) + [lonely], dtype=np.uint64)
+
+    clusters = cells_dbscan(cells, 1, 4.0)
+    assert isinstance(clusters, Array)
+    assert clusters.type == DataType.uint32()
+    assert len(clusters) == len(cells)
+    assert clusters[0].as_py() == 0
+    assert clusters[len(cells) - 1].as_py() is None
+
+
+def test_cells_dbscan_weights():
+    lonely = h3.latlng_to_cell(-20.3, 12.1, 8)
+    cells = np.array([lonely], dtype=np.uint64)
+
+    clusters = cells_dbscan(cells, 1, 4.0, weights=np.array([5.0]))
+    assert clusters[0].as_py() == 0
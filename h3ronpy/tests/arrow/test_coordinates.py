@@ -1,11 +1,19 @@
+from datetime import datetime, timedelta, timezone
+
 import h3.api.numpy_int as h3
 import numpy as np
+import pyarrow as pa
 from arro3.core import RecordBatch
+from h3ronpy import ContainmentMode
 from h3ronpy.vector import (
     cells_bounds,
     cells_bounds_arrays,
     cells_to_coordinates,
+    coordinates_to_cell_sequences,
     coordinates_to_cells,
+    coordinates_to_cells_within_radius,
+    coordinates_to_hexbins,
+    coordinates_to_spacetime_bins,
 )
 
 
@@ -42,6 +50,67 @@ def test_coordinates_to_cells_resolution_array():
     assert cells[1] == h3.latlng_to_cell(lat[1], lng[1], r[1])
 
 
+def test_coordinates_to_hexbins():
+    lat = np.array([10.3, 10.3001, 23.1], dtype=np.float64)
+    lng = np.array([45.1, 45.1001, 2.3], dtype=np.float64)
+    r = 7
+    hexbins = coordinates_to_hexbins(lat, lng, r, values={"v": np.array([1.0, 3.0, 5.0])})
+    assert isinstance(hexbins, RecordBatch)
+    assert hexbins.num_rows == 2
+    assert hexbins.schema.names == ["cell", "count", "v_sum", "v_mean", "v_min", "v_max"]
+
+    row = hexbins["cell"].to_numpy().tolist().index(h3.latlng_to_cell(lat[0], lng[0], r))
+    assert hexbins["count"][row].as_py() == 2
+    assert hexbins["v_sum"][row].as_py() == 4.0
+    assert hexbins["v_mean"][row].as_py() == 2.0
+    assert hexbins["v_min"][row].as_py() == 1.0
+    assert hexbins["v_max"][row].as_py() == 3.0
+
+
+def test_coordinates_to_spacetime_bins():
+    lat = np.array([10.3, 10.3, 10.3], dtype=np.float64)
+    lng = np.array([45.1, 45.1, 45.1], dtype=np.float64)
+    timestamps = pa.array(
+        [
+            datetime(2024, 1, 31, 12, 0, tzinfo=timezone.utc),
+            datetime(2024, 2, 1, 8, 0, tzinfo=timezone.utc),
+            datetime(2024, 2, 1, 9, 30, tzinfo=timezone.utc),
+        ],
+        type=pa.timestamp("s", tz="UTC"),
+    )
+    r = 7
+
+    bins = coordinates_to_spacetime_bins(lat, lng, timestamps, r, "month", values={"v": np.array([1.0, 2.0, 4.0])})
+    assert isinstance(bins, RecordBatch)
+    assert bins.num_rows == 2
+    assert bins.schema.names == ["cell", "bucket_start", "key", "count", "v_sum", "v_mean", "v_min", "v_max"]
+    assert bins["count"].to_numpy().tolist() == [1, 2]
+    assert bins["v_sum"].to_numpy().tolist() == [1.0, 6.0]
+
+    bins = coordinates_to_spacetime_bins(lat, lng, timestamps, r, timedelta(hours=1))
+    assert bins.num_rows == 3
+
+
+def test_coordinates_to_cell_sequences():
+    lat = np.array([10.3, 10.3, 10.3, 12.0, 20.0], dtype=np.float64)
+    lng = np.array([45.1, 45.1, 45.1, 46.0, 10.0], dtype=np.float64)
+    timestamps = pa.array([0, 10, 20, 100, 0], type=pa.timestamp("s"))
+    track_ids = pa.array(["a", "a", "a", "a", "b"])
+    r = 5
+
+    sequences = coordinates_to_cell_sequences(lat, lng, timestamps, r, track_ids=track_ids, fill_gaps=False)
+    assert isinstance(sequences, RecordBatch)
+    assert sequences.schema.names == ["track_id", "cell", "entry_time", "exit_time", "point_count"]
+    assert sequences.num_rows == 3
+    assert sequences["track_id"].to_pylist() == ["a", "a", "b"]
+    assert sequences["cell"][0].as_py() == h3.latlng_to_cell(lat[0], lng[0], r)
+    assert sequences["point_count"].to_numpy().tolist() == [3, 1, 1]
+
+    filled = coordinates_to_cell_sequences(lat, lng, timestamps, r, track_ids=track_ids)
+    assert filled.num_rows > sequences.num_rows
+    assert filled["point_count"].to_numpy().sum() == 5
+
+
 def test_cells_bounds():
     h3indexes = np.array(
         [
@@ -77,3 +146,40 @@ def test_cells_bounds_arrays():
     assert bounds_df["maxx"][0].as_py() > 45.1
     assert bounds_df["miny"][0].as_py() < 10.3
     assert bounds_df["maxy"][0].as_py() > 10.3
+
+
+def test_coordinates_to_cells_within_radius():
+    lat = np.array([78.2, 10.0, np.nan])
+    lng = np.array([15.6, 20.0, 0.0])
+    radius = np.array([10_000.0, 1.0, 5.0])
+    lat = pa.array(lat, mask=np.isnan(lat))
+
+    celllists = coordinates_to_cells_within_radius(lat, lng, radius, 7).to_pylist()
+    assert len(celllists) == 3
+    assert celllists[2] is None
+
+    # all centroids are located within the circle
+    for cell in celllists[0]:
+        cell_lat, cell_lng = h3.cell_to_latlng(cell)
+        assert h3.great_circle_distance((78.2, 15.6), (cell_lat, cell_lng), unit="m") <= 10_000.0
+
+    # the tiny circle does not contain any centroid, but intersects its own cell
+    assert celllists[1] == []
+    intersecting = coordinates_to_cells_within_radius(
+        [10.0], [20.0], [1.0], 7, containment_mode=ContainmentMode.IntersectsBoundary
+    ).to_pylist()
+    assert intersecting == [[h3.latlng_to_cell(10.0, 20.0, 7)]]
+
+
+def test_cells_bounds_arrays_antimeridian():
+    h3indexes = np.array(
+        [
+            h3.latlng_to_cell(0.0, 180.0, 3),
+        ],
+        dtype=np.uint64,
+    )
+    bounds = cells_bounds(h3indexes)
+    bounds_df = cells_bounds_arrays(h3indexes)
+    assert bounds_df["minx"][0].as_py() == bounds[0]
+    assert bounds_df["maxx"][0].as_py() == bounds[2]
+    assert bounds_df["maxx"][0].as_py() - bounds_df["minx"][0].as_py() < 5.0
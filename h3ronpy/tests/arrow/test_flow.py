@@ -0,0 +1,36 @@
+import h3.api.numpy_int as h3
+import numpy as np
+from arro3.core import RecordBatch
+from h3ronpy import cells_to_edge_flows, cells_to_od_flows
+
+
+def _path(start, end):
+    return list(h3.grid_path_cells(start, end))
+
+
+def test_cells_to_edge_flows():
+    start = h3.latlng_to_cell(10.3, 45.1, 5)
+    end = h3.latlng_to_cell(12.0, 46.0, 5)
+    path = _path(start, end)
+    cells = np.array(path + path, dtype=np.uint64)
+    track_ids = np.array([1] * len(path) + [2] * len(path), dtype=np.uint64)
+
+    flows = cells_to_edge_flows(cells, track_ids=track_ids)
+    assert isinstance(flows, RecordBatch)
+    assert flows.schema.names == ["edge", "count"]
+    assert flows.num_rows == len(path) - 1
+    assert set(flows["count"].to_numpy().tolist()) == {2}
+
+
+def test_cells_to_od_flows():
+    start = h3.latlng_to_cell(10.3, 45.1, 5)
+    end = h3.latlng_to_cell(12.0, 46.0, 5)
+    path = _path(start, end)
+    cells = np.array(path + path[::-1], dtype=np.uint64)
+    track_ids = np.array([1] * len(path) + [2] * len(path), dtype=np.uint64)
+
+    flows = cells_to_od_flows(cells, 2, track_ids=track_ids)
+    assert flows.schema.names == ["origin", "destination", "count"]
+    assert flows.num_rows == 2
+    assert flows["count"].to_numpy().tolist() == [1, 1]
+    assert set(flows["origin"].to_numpy().tolist()) == {h3.cell_to_parent(start, 2), h3.cell_to_parent(end, 2)}
@@ -1,7 +1,13 @@
 import h3.api.numpy_int as h3
 import numpy as np
 from arro3.core import Array
-from h3ronpy import cells_area_km2
+from h3ronpy import (
+    cells_area_km2,
+    cells_distance_km,
+    cells_distance_m,
+    cells_distance_to_coordinates_m,
+    cells_distance_to_point_m,
+)
 
 
 def test_cells_area_km2():
@@ -19,3 +25,25 @@ def test_cells_area_km2():
     assert int(areas[0].as_py() * 100) == 62
     assert int(areas[1].as_py()) == 213
     assert int(areas[2].as_py()) == 10456
+
+
+def test_cells_distance():
+    berlin = h3.latlng_to_cell(52.5, 13.4, 9)
+    paris = h3.latlng_to_cell(48.9, 2.35, 9)
+    cells = np.array([berlin, paris], dtype=np.uint64)
+    others = np.array([paris, paris], dtype=np.uint64)
+
+    distance_km = cells_distance_km(cells, others)
+    assert len(distance_km) == 2
+    assert 870 < distance_km[0].as_py() < 885
+    assert distance_km[1].as_py() < 1e-9
+
+    expected_m = h3.great_circle_distance(h3.cell_to_latlng(berlin), h3.cell_to_latlng(paris), unit="m")
+    assert abs(cells_distance_m(cells, others)[0].as_py() - expected_m) < 1.0
+
+    paris_lat, paris_lng = h3.cell_to_latlng(paris)
+    to_point = cells_distance_to_point_m(cells, paris_lat, paris_lng)
+    to_coordinates = cells_distance_to_coordinates_m(cells, [paris_lat, None], [paris_lng, paris_lng])
+    assert abs(to_point[0].as_py() - expected_m) < 1.0
+    assert abs(to_coordinates[0].as_py() - expected_m) < 1.0
+    assert not to_coordinates[1].is_valid
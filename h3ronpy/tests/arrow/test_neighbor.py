@@ -7,6 +7,7 @@ from h3ronpy import (
     grid_disk,
     grid_disk_aggregate_k,
     grid_disk_distances,
+    grid_disk_within_m,
     grid_ring_distances,
 )
 
@@ -93,3 +94,26 @@ def test_grid_disk_aggregate_k():
     assert disks["k"].type == pa.uint32()
 
     # TODO: check values
+
+
+def test_grid_disk_within_m():
+    cell = h3.latlng_to_cell(64.1, -21.9, 8)
+    h3indexes = np.array([cell, h3.latlng_to_cell(5.3, -5.1, 8)], dtype=np.uint64)
+
+    disks = grid_disk_within_m(h3indexes, 3000.0)
+    assert isinstance(disks, RecordBatch)
+    assert disks.schema.names == ["cell", "distance_m"]
+    assert disks.num_rows == 2
+
+    flat = grid_disk_within_m(h3indexes, 3000.0, flatten=True)
+    distances = flat["distance_m"].to_numpy()
+    assert len(distances) > 14
+    assert (distances <= 3000.0).all()
+
+    lat, lng = h3.cell_to_latlng(cell)
+    for grid_cell, distance in zip(disks["cell"][0].as_py(), disks["distance_m"][0].as_py()):
+        expected = h3.great_circle_distance((lat, lng), h3.cell_to_latlng(grid_cell), unit="m")
+        assert abs(distance - expected) < 1.0
+
+    boundary = grid_disk_within_m(h3indexes, 3000.0, distance_mode="boundary", flatten=True)
+    assert len(boundary) > len(flat)
@@ -6,12 +6,13 @@ except ImportError:
     # rasterio is an optional dependency
     HAS_RASTERIO = False
 
+import h3.api.numpy_int as h3
 import numpy as np
 import polars as pl
 import pyarrow as pa
 import pytest
 from h3ronpy import DEFAULT_CELL_COLUMN_NAME, H3_CRS
-from h3ronpy.raster import raster_to_dataframe, rasterize_cells
+from h3ronpy.raster import nearest_h3_resolution, raster_to_dataframe, rasterize_cells
 
 from tests import TESTDATA_PATH
 
@@ -84,3 +85,43 @@ def test_rasterize_cells_auto_aspect():
     # for inspection during debugging
     if False:
         write_gtiff("/tmp/rasterized_auto_aspect.tif", array, transform, nodata_value)
+
+
+def test_raster_to_dataframe_web_mercator():
+    # 40x20 pixels of 1km in web mercator, the left half has the value 1, the right half 2
+    in_raster = np.ones((20, 40), dtype=np.uint8)
+    in_raster[:, 20:] = 2
+    transform = (1_000_000.0, 1000.0, 0.0, 6_000_000.0, 0.0, -1000.0)
+
+    # the pixels cover about 0.46km² at this latitude
+    assert nearest_h3_resolution(in_raster.shape, transform, crs=3857) == 8
+    assert nearest_h3_resolution(in_raster.shape, transform, search_mode="smaller_than_pixel", crs=3857) == 9
+
+    df = raster_to_dataframe(in_raster, transform, 7, compact=False, crs="EPSG:3857")
+    # the number of res-7 cells with their centroid located within the raster
+    assert len(df) == 73
+    assert set(df["value"].to_pylist()) == {1, 2}
+
+    for value, cell in zip(df["value"].to_pylist(), df[DEFAULT_CELL_COLUMN_NAME].to_numpy()):
+        lat, lng = h3.cell_to_latlng(cell)
+        x = np.radians(lng) * 6378137.0
+        y = np.log(np.tan(np.pi / 4 + np.radians(lat) / 2)) * 6378137.0
+        assert 1_000_000.0 <= x < 1_040_000.0
+        assert 5_980_000.0 < y <= 6_000_000.0
+        assert (x < 1_020_000.0) == (value == 1)
+
+
+def test_raster_to_dataframe_web_mercator_world_extent():
+    # the full extent of web mercator, which spans 360° of longitude
+    extent = 20037508.342789244
+    in_raster = np.ones((4, 4), dtype=np.uint8)
+    transform = (-extent, extent / 2, 0.0, extent, 0.0, -extent / 2)
+
+    df = raster_to_dataframe(in_raster, transform, 1, compact=False, crs=3857)
+    # all res-1 cells except the few polar ones beyond the latitude limit of web mercator
+    assert len(df) > 800
+
+    lngs = np.array([h3.cell_to_latlng(cell)[1] for cell in df[DEFAULT_CELL_COLUMN_NAME].to_numpy()])
+    assert lngs.min() < -170.0
+    assert lngs.max() > 170.0
+    assert ((lngs > -10.0) & (lngs < 10.0)).any()
@@ -1,10 +1,35 @@
 import h3.api.numpy_int as h3
+import numpy as np
+import pytest
 import shapely
 from arro3.core import Array, DataType, Scalar
-from h3ronpy.vector import ContainmentMode, cells_to_wkb_points, geometry_to_cells
+from h3ronpy.vector import (
+    AntimeridianHandling,
+    AntimeridianMode,
+    ContainmentMode,
+    celllists_to_geoarrow_multipolygons,
+    celllists_to_wkb_multipolygons,
+    cells_to_ecef_boundaries,
+    cells_to_ecef_centroids,
+    cells_to_ecef_mesh,
+    cells_to_geoarrow_points,
+    cells_to_geoarrow_polygons,
+    cells_to_wkb_multipolygons_by_key,
+    cells_to_wkb_points,
+    cells_to_wkb_polygons,
+    directededges_to_geoarrow_linestrings,
+    directededges_to_wkb_linestrings,
+    geometry_to_cells,
+    wkb_areal_interpolation,
+    wkb_to_cell_fractions,
+    wkb_to_cells,
+    wkb_to_classified_cells,
+)
 from shapely import wkb
 from shapely.geometry import Point
 
+from .. import load_country
+
 
 def test_geometry_to_cells():
     geom = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)))
@@ -14,6 +39,41 @@ def test_geometry_to_cells():
     assert len(cells) > 10
 
 
+def test_geometry_to_cells_adaptive():
+    geom = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.5), (1.0, 0.0), (0.0, 0.0)))
+    compacted = geometry_to_cells(geom, 7, compact=True).to_numpy()
+    adaptive = geometry_to_cells(geom, 7, min_resolution=4).to_numpy()
+    np.testing.assert_array_equal(np.sort(adaptive), np.sort(compacted))
+    assert min(h3.get_resolution(cell) for cell in adaptive) < 7
+
+
+def test_geometry_to_cells_max_cells():
+    geom = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.5), (1.0, 0.0), (0.0, 0.0)))
+    covering = geometry_to_cells(geom, 8, min_resolution=3, max_cells=20).to_numpy()
+    assert 0 < len(covering) <= 20
+
+    # the covering contains the centroid of each cell within the polygon
+    covering = set(covering)
+    for cell in geometry_to_cells(geom, 6).to_numpy():
+        lat, lng = h3.cell_to_latlng(cell)
+        assert any(h3.latlng_to_cell(lat, lng, r) in covering for r in range(3, 9))
+
+
+def test_geometry_to_cells_line_buffer():
+    line = shapely.LineString(((10.0, 50.0), (10.3, 50.1), (10.5, 50.4)))
+    traced = set(geometry_to_cells(line, 8).to_numpy())
+
+    k_buffered = set(geometry_to_cells(line, 8, line_buffer_k=2).to_numpy())
+    assert traced < k_buffered
+
+    buffered = geometry_to_cells(line, 8, line_buffer_m=2000.0).to_numpy()
+    assert traced < set(buffered)
+    assert len(buffered) == len(set(buffered))
+
+    compacted = wkb_to_cells([shapely.to_wkb(line)], 8, compact=True, flatten=True, line_buffer_m=2000.0).to_numpy()
+    assert len(compacted) < len(buffered)
+
+
 def test_geometry_to_cells_central_park():
     # Manhattan Central Park
     point = Point(-73.9575, 40.7938)
@@ -23,6 +83,59 @@ def test_geometry_to_cells_central_park():
     assert arr[0] == h3.latlng_to_cell(point.y, point.x, 8)
 
 
+class WebMercator:
+    """coordinate transformation implemented in python, counting the number of calls"""
+
+    radius = 6378137.0
+
+    def __init__(self):
+        self.calls = 0
+
+    def to_wgs84(self, x, y):
+        self.calls += 1
+        return np.degrees(x / self.radius), np.degrees(2 * np.arctan(np.exp(y / self.radius)) - np.pi / 2)
+
+    def from_wgs84(self, x, y):
+        self.calls += 1
+        return np.radians(x) * self.radius, np.log(np.tan(np.pi / 4 + np.radians(y) / 2)) * self.radius
+
+
+def to_web_mercator(geom):
+    return shapely.transform(geom, lambda coords: np.column_stack(WebMercator().from_wgs84(coords[:, 0], coords[:, 1])))
+
+
+def test_geometry_to_cells_crs():
+    geom = shapely.Polygon(((10.0, 50.0), (10.0, 51.0), (11.0, 51.5), (11.0, 50.0), (10.0, 50.0)))
+    expected = np.sort(geometry_to_cells(geom, 6).to_numpy())
+    assert len(expected) > 10
+
+    projected = to_web_mercator(geom)
+    for crs in (3857, "EPSG:3857", WebMercator()):
+        np.testing.assert_array_equal(np.sort(geometry_to_cells(projected, 6, crs=crs).to_numpy()), expected)
+
+
+def test_wkb_to_cells_crs():
+    geoms = [
+        shapely.Polygon(((10.0, 50.0), (10.0, 51.0), (11.0, 51.5), (11.0, 50.0), (10.0, 50.0))),
+        shapely.LineString(((-73.9, 40.7), (-73.5, 40.9))),
+    ]
+    expected = wkb_to_cells([shapely.to_wkb(g) for g in geoms], 7, flatten=True).to_numpy()
+
+    projected = [shapely.to_wkb(to_web_mercator(g)) for g in geoms]
+    np.testing.assert_array_equal(wkb_to_cells(projected, 7, flatten=True, crs=3857).to_numpy(), expected)
+
+    # the coordinates of all geometries are transformed in a single call
+    transform = WebMercator()
+    np.testing.assert_array_equal(wkb_to_cells(projected, 7, flatten=True, crs=transform).to_numpy(), expected)
+    assert transform.calls == 1
+
+
+def test_geometry_to_cells_crs_unsupported():
+    geom = shapely.Point(10.0, 50.0)
+    with pytest.raises(ValueError):
+        geometry_to_cells(geom, 6, crs=object())
+
+
 def test_coordinate_values_are_not_equal_issue_58():
     # Step 1: Create a point (latitude and longitude)
     lat, lon = 37.7749, -122.4194  # Example coordinates (San Francisco)
@@ -43,3 +156,277 @@ def test_coordinate_values_are_not_equal_issue_58():
         shapely_point = wkb.loads(wkb_point.as_py())
         assert int(lat) == int(shapely_point.y)
         assert int(lon) == int(shapely_point.x)
+
+
+def test_cells_to_wkb_polygons_antimeridian():
+    cell = h3.latlng_to_cell(0.0, 180.0, 3)
+    cells = np.array([cell], dtype=np.uint64)
+
+    polygon = wkb.loads(cells_to_wkb_polygons(cells)[0].as_py())
+    assert polygon.bounds[2] - polygon.bounds[0] > 300
+
+    shifted = wkb.loads(cells_to_wkb_polygons(cells, antimeridian_mode=AntimeridianMode.Shift)[0].as_py())
+    assert shifted.geom_type == "Polygon"
+    assert shifted.bounds[2] - shifted.bounds[0] < 5
+    assert shifted.bounds[2] > 180
+
+    split = wkb.loads(cells_to_wkb_polygons(cells, antimeridian_mode=AntimeridianMode.Split)[0].as_py())
+    assert split.geom_type == "MultiPolygon"
+    assert len(split.geoms) == 2
+    assert split.bounds[0] >= -180
+    assert split.bounds[2] <= 180
+
+
+def test_cells_to_wkb_polygons_densified():
+    cell = h3.latlng_to_cell(50.0, 10.0, 0)
+    cells = np.array([cell], dtype=np.uint64)
+
+    polygon = wkb.loads(cells_to_wkb_polygons(cells)[0].as_py())
+    densified = wkb.loads(cells_to_wkb_polygons(cells, max_segment_length_m=10_000.0)[0].as_py())
+    assert len(densified.exterior.coords) > 10 * len(polygon.exterior.coords)
+    assert abs(densified.area - polygon.area) / polygon.area < 0.1
+
+    edges = np.array(h3.origin_to_directed_edges(cell), dtype=np.uint64)
+    for linestring, densified in zip(
+        directededges_to_wkb_linestrings(edges),
+        directededges_to_wkb_linestrings(edges, max_segment_angle_deg=0.5),
+    ):
+        linestring = wkb.loads(linestring.as_py())
+        densified = wkb.loads(densified.as_py())
+        assert len(densified.coords) > len(linestring.coords)
+        assert densified.coords[0] == linestring.coords[0]
+        assert densified.coords[-1] == linestring.coords[-1]
+
+    with pytest.raises(ValueError):
+        cells_to_wkb_polygons(cells, max_segment_length_m=10.0, max_segment_angle_deg=0.5)
+
+
+def test_cells_to_wkb_polygons_descendant_resolution():
+    cell = h3.latlng_to_cell(50.0, 10.0, 3)
+    children = np.array(h3.cell_to_children(cell, 6), dtype=np.uint64)
+    cells = np.array([cell], dtype=np.uint64)
+
+    outline = wkb.loads(cells_to_wkb_polygons(cells, descendant_resolution=6)[0].as_py())
+    dissolved = wkb.loads(cells_to_wkb_polygons(children, link_cells=True)[0].as_py())
+    assert outline.geom_type == "Polygon"
+    assert len(outline.exterior.coords) == len(dissolved.exterior.coords)
+    assert abs(outline.area - dissolved.area) / dissolved.area < 1e-9
+
+    parent = wkb.loads(cells_to_wkb_polygons(cells)[0].as_py())
+    assert abs(outline.area - parent.area) / parent.area > 1e-6
+
+    with pytest.raises(ValueError):
+        cells_to_wkb_polygons(cells, link_cells=True, descendant_resolution=6)
+
+
+def test_cells_to_wkb_polygons_crs():
+    cells = np.array([h3.latlng_to_cell(50.0, 10.0, 5), h3.latlng_to_cell(40.7, -73.9, 5)], dtype=np.uint64)
+    expected = [to_web_mercator(wkb.loads(g.as_py())) for g in cells_to_wkb_polygons(cells)]
+
+    transform = WebMercator()
+    for crs in (3857, transform):
+        polygons = [wkb.loads(g.as_py()) for g in cells_to_wkb_polygons(cells, crs=crs)]
+        assert len(polygons) == len(expected)
+        for polygon, expected_polygon in zip(polygons, expected):
+            np.testing.assert_allclose(
+                shapely.get_coordinates(polygon), shapely.get_coordinates(expected_polygon), rtol=0.0, atol=1e-3
+            )
+    assert transform.calls == 1
+
+
+def test_geometry_to_cells_antimeridian_split():
+    # Natural Earth splits Fiji at the antimeridian
+    fiji = load_country("Fiji")
+    cells = geometry_to_cells(fiji, 6, antimeridian_handling=AntimeridianHandling.Split).to_numpy()
+    lngs = np.array([h3.cell_to_latlng(cell)[1] for cell in cells])
+    assert (lngs > 179).any()
+    assert (lngs < -179).any()
+
+    # the same shape with longitudes exceeding 180°
+    unwrapped = shapely.transform(fiji, lambda coords: coords + np.where(coords[:, :1] < 0, [360.0, 0.0], 0.0))
+    unwrapped_cells = geometry_to_cells(unwrapped, 6, antimeridian_handling=AntimeridianHandling.Split).to_numpy()
+    np.testing.assert_array_equal(np.sort(cells), np.sort(unwrapped_cells))
+
+
+def test_geometry_to_cells_antimeridian_split_pole():
+    # Natural Earth closes the outline of Antarctica via the south pole
+    antarctica = load_country("Antarctica")
+    cells = geometry_to_cells(antarctica, 3, antimeridian_handling=AntimeridianHandling.Split).to_numpy()
+    for lng in (-179.0, -90.0, 0.0, 90.0, 179.0):
+        assert h3.latlng_to_cell(-85.0, lng, 3) in cells
+        assert h3.latlng_to_cell(-55.0, lng, 3) not in cells
+    assert h3.latlng_to_cell(-90.0, 0.0, 3) in cells
+
+
+def test_cells_to_wkb_multipolygons_by_key():
+    cell = h3.latlng_to_cell(10.3, 45.1, 6)
+    disk = h3.grid_disk(cell, 1)
+    other = h3.latlng_to_cell(-20.0, 10.0, 6)
+    cells = np.array(list(disk) + [other], dtype=np.uint64)
+    keys = np.array([2] * len(disk) + [1], dtype=np.int32)
+
+    grouped = cells_to_wkb_multipolygons_by_key(cells, keys)
+    assert grouped.schema.names == ["key", "geometry"]
+    assert grouped["key"].to_numpy().tolist() == [1, 2]
+
+    multipolygons = [wkb.loads(geom.as_py()) for geom in grouped["geometry"]]
+    assert all(mp.geom_type == "MultiPolygon" for mp in multipolygons)
+    assert len(multipolygons[1].geoms) == 1
+    assert len(multipolygons[1].geoms[0].interiors) == 0
+
+
+def test_celllists_to_wkb_multipolygons_roundtrip():
+    polygons = [
+        shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0))),
+        None,
+        shapely.Polygon(((10.0, 10.0), (10.0, 11.0), (11.0, 11.0), (11.0, 10.0), (10.0, 10.0))),
+    ]
+    celllists = wkb_to_cells([None if p is None else shapely.to_wkb(p) for p in polygons], 7, flatten=False)
+
+    multipolygons = celllists_to_wkb_multipolygons(celllists)
+    assert len(multipolygons) == 3
+    assert not multipolygons[1].is_valid
+
+    for polygon, mp in zip(polygons, multipolygons):
+        if polygon is None:
+            continue
+        mp = wkb.loads(mp.as_py())
+        assert mp.geom_type == "MultiPolygon"
+        assert abs(mp.area - polygon.area) / polygon.area < 0.05
+
+
+def _extension_name(arr: Array) -> bytes:
+    return arr.field.metadata[b"ARROW:extension:name"]
+
+
+def test_geoarrow_outputs():
+    cell = h3.latlng_to_cell(10.3, 45.1, 6)
+    cells = np.array([cell, h3.latlng_to_cell(-20.0, 10.0, 6)], dtype=np.uint64)
+
+    polygons = cells_to_geoarrow_polygons(cells)
+    assert len(polygons) == 2
+    assert _extension_name(polygons) == b"geoarrow.polygon"
+
+    points = cells_to_geoarrow_points(cells)
+    assert len(points) == 2
+    assert _extension_name(points) == b"geoarrow.point"
+
+    edges = np.array(h3.origin_to_directed_edges(cell), dtype=np.uint64)
+    linestrings = directededges_to_geoarrow_linestrings(edges)
+    assert len(linestrings) == len(edges)
+    assert _extension_name(linestrings) == b"geoarrow.linestring"
+
+    celllists = wkb_to_cells(cells_to_wkb_polygons(cells), 7, flatten=False)
+    multipolygons = celllists_to_geoarrow_multipolygons(celllists)
+    assert len(multipolygons) == 2
+    assert _extension_name(multipolygons) == b"geoarrow.multipolygon"
+
+
+def test_wkb_to_cells_geoarrow_and_wkt_input():
+    cells = np.array([h3.latlng_to_cell(10.3, 45.1, 6), h3.latlng_to_cell(-20.0, 10.0, 6)], dtype=np.uint64)
+
+    from_points = wkb_to_cells(cells_to_geoarrow_points(cells), 6, flatten=True).to_numpy()
+    np.testing.assert_array_equal(from_points, cells)
+
+    expected = wkb_to_cells(cells_to_wkb_polygons(cells), 6, flatten=False)
+    from_polygons = wkb_to_cells(cells_to_geoarrow_polygons(cells), 6, flatten=False)
+    assert from_polygons.to_pylist() == expected.to_pylist()
+
+    wkt = Array([shapely.to_wkt(wkb.loads(geom.as_py())) for geom in cells_to_wkb_polygons(cells)], DataType.string())
+    from_wkt = wkb_to_cells(wkt, 6, flatten=False)
+    assert from_wkt.to_pylist() == expected.to_pylist()
+
+
+def test_wkb_to_cell_fractions():
+    parent = h3.latlng_to_cell(10.3, 45.1, 5)
+    polygons = cells_to_wkb_polygons(np.array([parent], dtype=np.uint64))
+
+    batch = wkb_to_cell_fractions(polygons, 7)
+    assert batch.num_rows > 0
+    row_index = batch.column("row_index").to_numpy()
+    cells = batch.column("cell").to_numpy()
+    fractions = batch.column("fraction").to_numpy()
+    np.testing.assert_array_equal(row_index, np.zeros(len(cells), dtype=np.uint64))
+    assert np.all(fractions > 0.0)
+    assert np.all(fractions <= 1.0)
+    assert np.any(fractions < 1.0)
+
+    covered_area = sum(h3.cell_area(cell, unit="m^2") * fraction for cell, fraction in zip(cells, fractions))
+    assert abs(covered_area - h3.cell_area(parent, unit="m^2")) / h3.cell_area(parent, unit="m^2") < 0.01
+
+
+def test_wkb_areal_interpolation():
+    polygons = [
+        shapely.box(10.0, 10.0, 10.3, 10.3),
+        shapely.box(10.2, 10.2, 10.5, 10.5),
+    ]
+    batch = wkb_areal_interpolation(
+        Array([shapely.to_wkb(p) for p in polygons], DataType.binary()),
+        6,
+        extensive={"population": np.array([1000.0, 500.0])},
+        intensive={"density": np.array([2.0, 4.0])},
+    )
+    assert batch.schema.names == ["cell", "population", "density"]
+
+    # totals are preserved
+    assert abs(batch.column("population").to_numpy().sum() - 1500.0) < 1e-6
+
+    density = batch.column("density").to_numpy()
+    assert density.min() >= 2.0 - 1e-9
+    assert density.max() <= 4.0 + 1e-9
+
+
+def test_wkb_areal_interpolation_duplicate_names():
+    polygons = Array([shapely.to_wkb(shapely.box(10.0, 10.0, 10.3, 10.3))], DataType.binary())
+    with pytest.raises(ValueError):
+        wkb_areal_interpolation(
+            polygons,
+            6,
+            extensive={"population": np.array([1000.0])},
+            intensive={"population": np.array([2.0])},
+        )
+
+
+def test_wkb_to_classified_cells():
+    geom = shapely.Polygon(((0.0, 0.0), (0.0, 1.0), (1.0, 1.5), (1.0, 0.0), (0.0, 0.0)))
+    arr = Array([shapely.to_wkb(geom), None], DataType.binary())
+
+    batch = wkb_to_classified_cells(arr, 6, flatten=True)
+    cells = batch.column("cell").to_numpy()
+    is_interior = batch.column("is_interior").to_pylist()
+    np.testing.assert_array_equal(
+        np.sort(cells),
+        np.sort(geometry_to_cells(geom, 6, containment_mode=ContainmentMode.IntersectsBoundary).to_numpy()),
+    )
+    np.testing.assert_array_equal(
+        np.sort(cells[np.array(is_interior)]),
+        np.sort(geometry_to_cells(geom, 6, containment_mode=ContainmentMode.ContainsBoundary).to_numpy()),
+    )
+
+    batch = wkb_to_classified_cells(arr, 6)
+    assert batch.num_rows == 2
+    assert batch.column("cell").to_pylist()[1] is None
+    assert len(batch.column("is_interior").to_pylist()[0]) == len(cells)
+
+
+def test_cells_to_ecef():
+    cell = h3.latlng_to_cell(45.0, 10.0, 5)
+    cells = np.array(h3.grid_disk(cell, 1), dtype=np.uint64)
+
+    centroids = cells_to_ecef_centroids(cells)
+    assert len(centroids) == len(cells)
+    radii = np.linalg.norm(np.array([c.as_py() for c in centroids]), axis=1)
+    assert ((radii > 6_356_000) & (radii < 6_379_000)).all()
+
+    boundaries = cells_to_ecef_boundaries(cells)
+    assert len(boundaries) == len(cells)
+    assert all(len(boundary.as_py()) == 6 for boundary in boundaries)
+
+    vertices, indices, triangle_cells = cells_to_ecef_mesh(cells)
+    assert len(vertices) == 7 + 24
+    assert len(indices) == 7 * 6 * 3
+    assert len(triangle_cells) == 7 * 6
+    assert indices.to_numpy().max() < len(vertices)
+
+    vertices, _, _ = cells_to_ecef_mesh(cells, shared_vertices=False)
+    assert len(vertices) == 7 * 7
@@ -62,3 +62,31 @@ def test_series():
     assert s.h3.cells_resolution()[0] == 8
 
     assert s.h3.change_resolution(5)[0] == 600436446234411007
+
+
+def test_expr_cells_distance():
+    cell = h3.latlng_to_cell(10.3, 45.1, 8)
+    lat, lng = h3.cell_to_latlng(cell)
+    df = (
+        pl.DataFrame(
+            {
+                "cells": some_cell_series(),
+                "others": pl.Series(np.array([h3.latlng_to_cell(10.5, 45.1, 8)], dtype=np.uint64)),
+                "lat": [lat],
+                "lng": [lng],
+            }
+        )
+        .lazy()
+        .with_columns(
+            [
+                pl.col("cells").h3.cells_distance_km("others").alias("distance_km"),
+                pl.col("cells").h3.cells_distance_to_coordinates_m("lat", "lng").alias("to_coordinates"),
+                pl.col("cells").h3.cells_distance_to_point_m(lat, lng).alias("to_point"),
+            ]
+        )
+        .collect()
+    )
+    assert df["distance_km"].dtype == pl.Float64
+    assert 20.0 < df["distance_km"][0] < 25.0
+    assert df["to_coordinates"][0] < 1e-6
+    assert df["to_point"][0] < 1e-6
@@ -9,3 +9,11 @@ def test_series_cells_resolution():
     resolution = some_cell_series().h3.cells_resolution()
     assert resolution.dtype == pl.UInt8
     assert resolution[0] == 8
+
+
+def test_series_cells_distance():
+    cells = some_cell_series()
+    distance = cells.h3.cells_distance_m(cells)
+    assert distance.dtype == pl.Float64
+    assert distance[0] < 1e-6
+    assert cells.h3.cells_distance_to_point_rads(0.0, 0.0, radians=True)[0] > 0.0